@@ -4,7 +4,8 @@
  * Command-line interface definitions using clap.
  */
 
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(name = "lmo")]
@@ -16,22 +17,82 @@ pub struct Cli {
     #[arg(short = 's', long, env = "LMO_SERVER_URL")]
     pub server_url: Option<String>,
 
-    /// Enable verbose logging
-    #[arg(short, long, global = true)]
-    pub verbose: bool,
+    /// Read configuration from this file instead of ~/.config/lmo/config.toml
+    #[arg(long, global = true, env = "LMO_CONFIG", value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Request timeout in seconds, 0 to disable [default: 30]. Chat and download
+    /// streams are exempt
+    #[arg(long, global = true, value_name = "SECS")]
+    pub timeout: Option<u64>,
+
+    /// Retry idempotent requests (health, model lists) this many times on connection errors
+    #[arg(long, global = true, value_name = "N")]
+    pub retries: Option<u32>,
+
+    /// Bearer token for servers that require authentication
+    #[arg(long, visible_alias = "token", global = true, env = "LMO_API_KEY", hide_env_values = true, value_name = "TOKEN")]
+    pub api_key: Option<String>,
 
-    /// Output format (json, table, yaml)
-    #[arg(short = 'o', long, global = true, default_value = "table")]
-    pub output: String,
+    /// Use the settings of this [profiles.<name>] section of the config file
+    #[arg(long, global = true, env = "LMO_PROFILE", value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Increase diagnostic detail: -v info, -vv debug (with request timing), -vvv trace
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Log level for internal diagnostics (overrides RUST_LOG)
+    #[arg(long, global = true, value_enum)]
+    pub log_level: Option<LogLevel>,
+
+    /// Output format (json, table, yaml) [default: from config]
+    #[arg(short = 'o', long, global = true)]
+    pub output: Option<String>,
 
     /// Disable colors in output
     #[arg(long, global = true)]
     pub no_color: bool,
 
+    /// Only print warnings, errors and the requested data
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Send an anonymized failure report to this URL when a command fails (opt-in)
+    #[arg(long, global = true, env = "LMO_REPORT_ERRORS", value_name = "URL")]
+    pub report_errors: Option<String>,
+
+    /// Print the failure report that would be sent instead of sending it
+    #[arg(long, global = true, requires = "report_errors")]
+    pub report_errors_dry_run: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// Log levels accepted by --log-level
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Filter directive understood by tracing's EnvFilter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// List and search available models
@@ -47,6 +108,7 @@ pub enum Commands {
     Unload(UnloadCommand),
     
     /// Show status of loaded models
+    #[command(visible_alias = "ps")]
     Status(StatusCommand),
     
     /// Manage CLI configuration
@@ -57,6 +119,63 @@ pub enum Commands {
     
     /// Download a model from remote repository
     Download(DownloadCommand),
+    
+    /// Find orphaned files and missing entries in the model directory
+    Scan(ScanCommand),
+    
+    /// Register an existing local model file with the server
+    Import(ImportCommand),
+    
+    /// Inspect saved chat conversations
+    History(HistoryCommand),
+    
+    /// Show which local file a model reference resolves to, without loading it
+    Resolve(ResolveCommand),
+    
+    /// Show everything known about a single model
+    Info(InfoCommand),
+    
+    /// Print a shell completion script (e.g. `lmo completions zsh > ~/.zfunc/_lmo`)
+    Completions(CompletionsCommand),
+    
+    /// Generate text embeddings
+    Embeddings(EmbeddingsCommand),
+    
+    /// Count the tokens in a text, optionally listing them
+    Tokenize(TokenizeCommand),
+    
+    /// Continue a raw prompt without a chat template
+    Complete(CompleteCommand),
+    
+    /// Live view of server health, loaded models and active downloads
+    #[command(visible_alias = "watch")]
+    Dashboard(DashboardCommand),
+}
+
+impl Commands {
+    /// Subcommand name, as typed on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            Commands::Models(_) => "models",
+            Commands::Chat(_) => "chat",
+            Commands::Load(_) => "load",
+            Commands::Unload(_) => "unload",
+            Commands::Status(_) => "status",
+            Commands::Config(_) => "config",
+            Commands::Health(_) => "health",
+            Commands::Download(_) => "download",
+            Commands::Scan(_) => "scan",
+            Commands::Import(_) => "import",
+            Commands::History(_) => "history",
+            Commands::Resolve(_) => "resolve",
+            Commands::Info(_) => "info",
+            Commands::Completions(_) => "completions",
+            Commands::Embeddings(_) => "embeddings",
+            Commands::Tokenize(_) => "tokenize",
+            Commands::Complete(_) => "complete",
+            Commands::Dashboard(_) => "dashboard",
+        }
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -85,13 +204,77 @@ pub struct ModelsCommand {
     #[arg(short, long, default_value = "20")]
     pub limit: u32,
 
-    /// Sort by field (downloads, author, created, size)
+    /// Page of results to show (1-based); --limit is ignored when paging
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub page: Option<u32>,
+
+    /// Results per page [default: --limit]
+    #[arg(long, requires = "page", value_parser = clap::value_parser!(u32).range(1..))]
+    pub page_size: Option<u32>,
+
+    /// Sort by field (downloads, author, created, name, size)
     #[arg(long, default_value = "downloads")]
     pub sort: String,
 
     /// Sort direction (asc, desc)
     #[arg(long, default_value = "desc")]
     pub direction: String,
+
+    /// Only show remote models with at least this many downloads
+    #[arg(long)]
+    pub min_downloads: Option<u64>,
+
+    /// Filter by model format (e.g. gguf, safetensors)
+    #[arg(long)]
+    pub format: Option<String>,
+
+    /// Only show remote models that are downloaded (fully or partially), marked in a Local column
+    #[arg(long, conflicts_with = "local")]
+    pub downloaded: bool,
+
+    /// Only show local models at least this large (e.g. 500MB, 4GB)
+    #[arg(long, value_parser = parse_size, requires = "local")]
+    pub min_size: Option<u64>,
+
+    /// Only show local models at most this large (e.g. 500MB, 4GB)
+    #[arg(long, value_parser = parse_size, requires = "local")]
+    pub max_size: Option<u64>,
+
+    /// Table columns to show, in order (e.g. id,author,downloads); see the error for valid names
+    #[arg(long, value_delimiter = ',')]
+    pub columns: Vec<String>,
+
+    /// Extra metadata keys to show as columns for local models (e.g. quantization,parameter_count)
+    #[arg(long, value_delimiter = ',', requires = "local")]
+    pub show_metadata: Vec<String>,
+
+    /// Only print the number of models matching the filters
+    #[arg(long)]
+    pub count_only: bool,
+
+    /// Exit with a non-zero code when no models match
+    #[arg(long)]
+    pub fail_on_empty: bool,
+
+    /// Write the results to a CSV file instead of printing them
+    #[arg(long, value_name = "PATH")]
+    pub csv: Option<String>,
+
+    /// Print the results as well when writing --csv
+    #[arg(long, requires = "csv")]
+    pub also_stdout: bool,
+
+    /// Print one compact JSON object per model per line (NDJSON) instead of a table
+    #[arg(long, conflicts_with = "count_only")]
+    pub json_lines: bool,
+
+    /// Fetch the remote model list live, without reading or writing the cache
+    #[arg(long, conflicts_with = "refresh_cache")]
+    pub no_cache: bool,
+
+    /// Fetch the remote model list live and update the cache
+    #[arg(long)]
+    pub refresh_cache: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -108,53 +291,260 @@ pub struct ChatCommand {
     #[arg(short = 'i', long)]
     pub input: Option<String>,
 
-    /// Maximum tokens to generate
+    /// Send the clipboard contents as a single message (non-interactive mode)
+    #[arg(long, conflicts_with = "input")]
+    pub from_clipboard: bool,
+
+    /// Maximum tokens to generate, or "auto" to fill the remaining context
     #[arg(long, default_value = "1000")]
-    pub max_tokens: u32,
+    pub max_tokens: MaxTokens,
+
+    /// Sampling preset; individual sampling flags override its values
+    #[arg(long, value_enum)]
+    pub preset: Option<SamplingPreset>,
+
+    /// Temperature for sampling (0.0 to 2.0) [default: 0.7]
+    #[arg(short, long)]
+    pub temperature: Option<f32>,
+
+    /// Nucleus sampling probability mass (0.0 to 1.0)
+    #[arg(long, value_parser = parse_top_p)]
+    pub top_p: Option<f32>,
+
+    /// Penalize tokens that have already appeared at all (-2.0 to 2.0)
+    #[arg(long, allow_negative_numbers = true, value_parser = parse_penalty)]
+    pub presence_penalty: Option<f32>,
+
+    /// Penalize tokens by how often they have appeared (-2.0 to 2.0)
+    #[arg(long, allow_negative_numbers = true, value_parser = parse_penalty)]
+    pub frequency_penalty: Option<f32>,
+
+    /// Seed for reproducible sampling
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Stop generating when this sequence appears (repeatable)
+    #[arg(long)]
+    pub stop: Vec<String>,
+
+    /// Warn when the conversation is estimated to exceed this many tokens
+    #[arg(long)]
+    pub context_limit: Option<u32>,
 
-    /// Temperature for sampling (0.0 to 2.0)
-    #[arg(short, long, default_value = "0.7")]
-    pub temperature: f32,
+    /// Drop the oldest messages (never the system prompt) to stay within --context-limit
+    #[arg(long, requires = "context_limit")]
+    pub auto_trim: bool,
 
     /// Enable streaming output
     #[arg(long)]
     pub stream: bool,
 
+    /// Number of candidate replies to generate per prompt; all are shown, one is kept in history
+    #[arg(short = 'n', long = "n", visible_alias = "repeat", value_name = "COUNT", default_value_t = 1,
+          value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "stream")]
+    pub n: u32,
+
+    /// Render assistant replies as styled markdown (no effect without colors)
+    #[arg(long, conflicts_with = "stream")]
+    pub render: bool,
+
     /// Load conversation history from file
     #[arg(long)]
     pub load_history: Option<String>,
 
-    /// Save conversation history to file
+    /// Save conversation history to file (.json, .md, or .txt)
     #[arg(long)]
     pub save_history: Option<String>,
+
+    /// Allow --save-history to replace an existing file that isn't a conversation
+    #[arg(long, requires = "save_history")]
+    pub overwrite: bool,
+
+    /// Named session to resume and save on exit (stored under the lmo data directory)
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["load_history", "save_history", "input", "from_clipboard"])]
+    pub session: Option<String>,
+
+    /// Save the session after every reply, not just on exit
+    #[arg(long, requires = "session")]
+    pub autosave: bool,
+
+    /// List saved chat sessions and exit
+    #[arg(long)]
+    pub list_sessions: bool,
+
+    /// Seed the start of the assistant's reply (requires server prefill support)
+    #[arg(long)]
+    pub assistant_prefix: Option<String>,
+
+    /// Print a one-paragraph summary of the conversation on exit
+    #[arg(long)]
+    pub summarize_on_exit: bool,
+
+    /// Extract and validate the JSON value in the reply, failing if there is none
+    #[arg(long)]
+    pub strict_json: bool,
+
+    /// Prompt template file with {system}, {messages} and {user} placeholders; the
+    /// formatted conversation is sent to the raw completion endpoint
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["stream", "strict_json", "n"])]
+    pub template: Option<String>,
+
+    /// JSON Schema file the reply must satisfy (with --strict-json)
+    #[arg(long, requires = "strict_json")]
+    pub json_schema: Option<String>,
+}
+
+/// Parse a human-readable size such as "500MB" or "4gb" into bytes (1KB = 1024B)
+fn parse_size(s: &str) -> Result<u64, String> {
+    let trimmed = s.trim();
+    let split = trimmed.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    
+    let number: f64 = number.parse()
+        .map_err(|_| format!("expected a size like 500MB or 4GB, got '{}'", s))?;
+    let multiplier: u64 = match unit.trim().to_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" | "K" => 1 << 10,
+        "MB" | "M" => 1 << 20,
+        "GB" | "G" => 1 << 30,
+        "TB" | "T" => 1 << 40,
+        other => return Err(format!("unknown size unit '{}' (use B, KB, MB, GB, or TB)", other)),
+    };
+    
+    Ok((number * multiplier as f64) as u64)
+}
+
+/// Parse --top-p, which must lie within 0.0..=1.0
+fn parse_top_p(s: &str) -> Result<f32, String> {
+    let value: f32 = s.parse()
+        .map_err(|_| format!("expected a number between 0.0 and 1.0, got '{}'", s))?;
+    
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!("top_p must be between 0.0 and 1.0, got {}", value))
+    }
+}
+
+/// Parse --presence-penalty and --frequency-penalty, which must lie within -2.0..=2.0
+fn parse_penalty(s: &str) -> Result<f32, String> {
+    let value: f32 = s.parse()
+        .map_err(|_| format!("expected a number between -2.0 and 2.0, got '{}'", s))?;
+    
+    if (-2.0..=2.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!("penalty must be between -2.0 and 2.0, got {}", value))
+    }
+}
+
+/// Curated sampling parameter bundles for --preset
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SamplingPreset {
+    Creative,
+    Balanced,
+    Precise,
+    Deterministic,
+}
+
+/// Download progress reporting for --progress
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgressMode {
+    Bar,
+    Json,
+}
+
+/// Token budget for a chat completion
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaxTokens {
+    /// Fill whatever context remains after the prompt
+    Auto,
+    /// Fixed number of tokens
+    Fixed(u32),
+}
+
+impl std::str::FromStr for MaxTokens {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(MaxTokens::Auto);
+        }
+        
+        s.parse::<u32>()
+            .map(MaxTokens::Fixed)
+            .map_err(|_| format!("expected a number of tokens or 'auto', got '{}'", s))
+    }
 }
 
 #[derive(Parser, Debug)]
 pub struct LoadCommand {
     /// Model identifier to load
-    pub model_id: String,
+    #[arg(required_unless_present = "pick")]
+    pub model_id: Option<String>,
+
+    /// Choose the model to load from an interactive, filterable list
+    #[arg(long, conflicts_with = "model_id")]
+    pub pick: bool,
 
     /// Specific filename to load (optional)
     #[arg(short = 'F', long)]
     pub filename: Option<String>,
 
+    /// Number of layers to offload to the GPU (server default when omitted)
+    #[arg(long)]
+    pub gpu_layers: Option<u32>,
+
+    /// Context window size in tokens (server default when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub context_size: Option<u32>,
+
+    /// Memory cap for the model in GB (server default when omitted)
+    #[arg(long, value_name = "GB")]
+    pub max_memory: Option<f64>,
+
     /// Force reload if already loaded
     #[arg(short, long)]
     pub force: bool,
 
+    /// Confirm reloading a model that is already loaded
+    #[arg(short, long)]
+    pub yes: bool,
+
     /// Show loading progress
     #[arg(short, long)]
     pub progress: bool,
+
+    /// Wait until the model has finished loading (or failed) before exiting
+    #[arg(short, long)]
+    pub wait: bool,
+
+    /// Give up waiting after this many seconds
+    #[arg(long, default_value = "300", requires = "wait", value_parser = clap::value_parser!(u64).range(1..))]
+    pub wait_timeout: u64,
+
+    /// Ring the bell and send a desktop notification when done
+    #[arg(long)]
+    pub notify: bool,
 }
 
 #[derive(Parser, Debug)]
 pub struct UnloadCommand {
     /// Model instance ID to unload
-    pub instance_id: String,
+    #[arg(required_unless_present = "model")]
+    pub instance_id: Option<String>,
+
+    /// Unload every loaded instance of this model id instead
+    #[arg(short, long, conflicts_with = "instance_id")]
+    pub model: Option<String>,
 
     /// Force unload even if in use
     #[arg(short, long)]
     pub force: bool,
+
+    /// Unload without asking for confirmation
+    #[arg(short, long)]
+    pub yes: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -163,13 +553,25 @@ pub struct StatusCommand {
     #[arg(short, long)]
     pub detailed: bool,
 
-    /// Show only specific model
+    /// Show the loaded instances of a specific model (model or instance id)
     #[arg(short, long)]
     pub model: Option<String>,
 
     /// Refresh interval in seconds (for watch mode)
     #[arg(short, long)]
     pub refresh: Option<u64>,
+
+    /// Exit with code 3 when no model instances are loaded
+    #[arg(long)]
+    pub require_loaded: bool,
+
+    /// Maximum number of loaded instances to list
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Number of loaded instances to skip before listing
+    #[arg(long, default_value = "0")]
+    pub offset: usize,
 }
 
 #[derive(Parser, Debug)]
@@ -195,6 +597,9 @@ pub enum ConfigAction {
     Get {
         /// Configuration key
         key: String,
+        /// Print secrets such as auth_token instead of masking them
+        #[arg(long)]
+        reveal: bool,
     },
     
     /// Initialize configuration with defaults
@@ -202,6 +607,9 @@ pub enum ConfigAction {
     
     /// Reset configuration to defaults
     Reset,
+    
+    /// List the server profiles defined in the config file
+    ListProfiles,
 }
 
 #[derive(Parser, Debug)]
@@ -213,26 +621,270 @@ pub struct HealthCommand {
     /// Check specific health aspects (server, models, memory)
     #[arg(short, long)]
     pub check: Vec<String>,
+
+    /// Treat a degraded server as a failure (exit code 4), not just an unhealthy one
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Poll until the server reports healthy instead of checking once
+    #[arg(short, long)]
+    pub wait: bool,
+
+    /// Give up waiting after this many seconds
+    #[arg(long, default_value = "60", requires = "wait", value_parser = clap::value_parser!(u64).range(1..))]
+    pub wait_timeout: u64,
 }
 
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct DownloadCommand {
-    /// Model name to download (e.g., "microsoft/DialoGPT-small")
-    pub model_name: String,
+    #[command(subcommand)]
+    pub action: Option<DownloadAction>,
+
+    /// Model names to download (e.g., "microsoft/DialoGPT-small")
+    #[arg(required = true)]
+    pub model_names: Vec<String>,
+
+    /// Number of models to download at once when several are given
+    #[arg(long, default_value = "1", value_parser = clap::value_parser!(u32).range(1..=8))]
+    pub parallel: u32,
 
     /// Model format hint (gguf, mlx, safetensors, candle)
     #[arg(short, long)]
     pub format: Option<String>,
 
-    /// Show download progress
-    #[arg(short, long)]
-    pub progress: bool,
+    /// How to report download progress (bar, or one JSON object per event on stdout)
+    #[arg(short, long, value_enum, default_value_t = ProgressMode::Bar)]
+    pub progress: ProgressMode,
 
     /// Force re-download if already exists
     #[arg(long)]
     pub force: bool,
 
+    /// Continue an unfinished download of this model instead of starting over
+    #[arg(long, conflicts_with = "force")]
+    pub resume: bool,
+
+    /// Skip SHA256 verification of the downloaded files
+    #[arg(long)]
+    pub no_verify: bool,
+
+    /// Seconds without progress updates before a stall is counted
+    #[arg(long, default_value = "30", value_parser = clap::value_parser!(u64).range(1..))]
+    pub stream_timeout: u64,
+
+    /// Consecutive stalls tolerated before the progress stream is abandoned
+    #[arg(long, default_value = "3", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_stalls: u32,
+
     /// Custom download directory (optional)
     #[arg(short, long)]
     pub directory: Option<String>,
+
+    /// Mirror base URL to download from instead of the default hub
+    #[arg(long, env = "HF_ENDPOINT")]
+    pub mirror: Option<String>,
+
+    /// Number of files the server fetches concurrently (1-16)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=16))]
+    pub parallel_files: Option<u32>,
+
+    /// Ring the bell and send a desktop notification when done
+    #[arg(long)]
+    pub notify: bool,
+
+    /// Cap the download rate, per second (e.g. 5MB, 500KB)
+    #[arg(long, value_parser = parse_size, value_name = "RATE")]
+    pub max_speed: Option<u64>,
+
+    /// Only download repository files matching this glob (repeatable, e.g. "*Q4_K_M*")
+    #[arg(long = "file", value_name = "PATTERN")]
+    pub files: Vec<String>,
+
+    /// List the repository's quantized files and recommend one that fits in memory
+    #[arg(long, conflicts_with_all = ["parallel", "files"])]
+    pub recommend: bool,
+
+    /// Memory available for the model in GB (used by --recommend)
+    #[arg(long, requires = "recommend")]
+    pub max_memory_gb: Option<f64>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DownloadAction {
+    /// List in-flight and queued downloads, including those started elsewhere
+    List {
+        /// Live view where downloads can be paused, resumed, or cancelled
+        #[arg(short, long)]
+        interactive: bool,
+    },
+
+    /// Pause an active download
+    Pause {
+        /// Download ID to pause
+        download_id: String,
+    },
+
+    /// Resume a paused download and follow its progress
+    Resume {
+        /// Download ID to resume
+        download_id: String,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct ScanCommand {
+    /// Model directory to scan
+    #[arg(long, env = "LMO_MODEL_DIR")]
+    pub model_dir: String,
+
+    /// Delete files on disk that the registry doesn't know about (asks for confirmation)
+    #[arg(long)]
+    pub prune_orphans: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct ImportCommand {
+    /// Path to the model file to import
+    pub path: String,
+
+    /// Move the file into the model directory instead of copying it
+    #[arg(long = "move")]
+    pub move_file: bool,
+
+    /// Registry id to register the model under (defaults to the filename)
+    #[arg(short, long)]
+    pub name: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct HistoryCommand {
+    #[command(subcommand)]
+    pub action: HistoryAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HistoryAction {
+    /// Compare two saved conversations and show where they diverge
+    Diff {
+        /// First conversation file
+        a: String,
+        /// Second conversation file
+        b: String,
+        /// Compare only the assistant responses
+        #[arg(long)]
+        only_assistant: bool,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct ResolveCommand {
+    /// Model reference to resolve (e.g. org/model or org/model:Q4_K_M)
+    pub model_id: String,
+
+    /// Quantization to select (same as an `org/model:QUANT` suffix); no short
+    /// flag, since `-q` is the global --quiet
+    #[arg(long)]
+    pub quant: Option<String>,
+
+    /// Specific filename to resolve (same as `load --filename`)
+    #[arg(short = 'F', long)]
+    pub filename: Option<String>,
+
+    /// Print the result as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct InfoCommand {
+    /// Model ID (remote) or filename (local)
+    pub model_id: String,
+
+    /// Only look at local models
+    #[arg(long, conflicts_with = "remote")]
+    pub local: bool,
+
+    /// Only look at the remote registry
+    #[arg(long)]
+    pub remote: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct EmbeddingsCommand {
+    /// Text to embed (read from stdin when omitted)
+    #[arg(conflicts_with = "file")]
+    pub text: Option<String>,
+
+    /// Model to use [default: default_model from the config file]
+    #[arg(short, long)]
+    pub model: Option<String>,
+
+    /// Read inputs from a file, one embedding per non-empty line
+    #[arg(short, long)]
+    pub file: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct TokenizeCommand {
+    /// Text to tokenize (read from stdin when omitted)
+    #[arg(conflicts_with = "file")]
+    pub text: Option<String>,
+
+    /// Model whose tokenizer to use [default: default_model from the config file]
+    #[arg(short, long)]
+    pub model: Option<String>,
+
+    /// Read the text from a file
+    #[arg(short, long)]
+    pub file: Option<String>,
+
+    /// List each token id with its decoded piece
+    #[arg(long)]
+    pub show_tokens: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct CompleteCommand {
+    /// Prompt to continue (read from stdin when omitted)
+    #[arg(conflicts_with = "file")]
+    pub prompt: Option<String>,
+
+    /// Model to use [default: default_model from the config file]
+    #[arg(short, long)]
+    pub model: Option<String>,
+
+    /// Read the prompt from a file
+    #[arg(short, long)]
+    pub file: Option<String>,
+
+    /// Maximum tokens to generate [default: chat.max_tokens from the config file]
+    #[arg(long)]
+    pub max_tokens: Option<u32>,
+
+    /// Temperature for sampling [default: chat.temperature from the config file]
+    #[arg(short, long)]
+    pub temperature: Option<f32>,
+
+    /// Stop generating at this sequence (repeatable)
+    #[arg(long)]
+    pub stop: Vec<String>,
+
+    /// Print the continuation as it is generated
+    #[arg(long)]
+    pub stream: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct CompletionsCommand {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    pub shell: clap_complete::Shell,
+}
+
+#[derive(Parser, Debug)]
+pub struct DashboardCommand {
+    /// Seconds between refreshes
+    #[arg(short, long, default_value = "2", value_name = "SECS")]
+    pub interval: u64,
 }
\ No newline at end of file
@@ -5,24 +5,68 @@
  */
 
 use anyhow::{Context, Result};
+use futures::StreamExt;
+use indicatif::ProgressBar;
 use lmoclient::{LmoClient, models::LoadModelRequest};
-use lmoserver::shared_types::{ChatCompletionRequest, ChatMessage};
-use std::io::{self, Write};
+use lmoserver::shared_types::{ChatCompletionRequest, ChatMessage, CompletionRequest};
+use chrono::{DateTime, Local};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::signal;
+use tracing::debug;
 
-use crate::cli::ChatCommand;
+use crate::cli::{ChatCommand, MaxTokens, SamplingPreset};
 use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::model_ref::ModelRef;
 use crate::output::OutputFormatter;
+use crate::utils::{create_client, create_streaming_client, fetch_loaded_models, read_clipboard, retry_transient, spinner, timed};
 
-pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Result<()> {
+pub async fn handle(cmd: ChatCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
     
-    // Create client
-    let client = LmoClient::with_url(&config.server_url)
+    if cmd.list_sessions {
+        return list_sessions(config, &output);
+    }
+    
+    // Resolve the session file before any server calls so a bad name fails fast
+    let session_path = cmd.session.as_deref().map(session_file).transpose()?;
+    
+    // Read the clipboard up front so a headless session fails before any server calls
+    let single_message = if cmd.from_clipboard {
+        Some(read_clipboard()?)
+    } else if cmd.input.is_none() && !io::stdin().is_terminal() {
+        // Piped input is sent as one message, e.g. `echo "hi" | lmo chat`
+        let mut piped = String::new();
+        io::stdin().read_to_string(&mut piped)
+            .context("Failed to read message from stdin")?;
+        
+        if piped.trim().is_empty() {
+            return Err(CliError::InvalidInput("No message received on stdin".to_string()).into());
+        }
+        Some(piped.trim_end().to_string())
+    } else {
+        cmd.input.clone()
+    };
+    
+    // Check the save target now rather than losing the conversation at the end
+    if let Some(ref save_path) = cmd.save_history {
+        check_history_target(save_path, cmd.overwrite)?;
+    }
+    
+    // Likewise, a bad schema should fail before anything is generated
+    let json_schema = cmd.json_schema.as_deref().map(load_json_schema).transpose()?;
+    let chat_template = cmd.template.as_deref().map(ChatTemplate::load).transpose()?;
+    
+    // Create clients; streamed replies can outlast the request timeout
+    let client = create_client(config, None)
+        .context("Failed to create LMO client")?;
+    let stream_client = create_streaming_client(config)
         .context("Failed to create LMO client")?;
     
-    // Check server health
-    output.status("Checking server health...");
-    match client.health().await {
+    // Check server health, giving a server that is still starting a moment to come up
+    match check_chat_health(&client, &output).await {
         Ok(_) => output.success("Server is healthy"),
         Err(e) => {
             output.error(&format!("Server health check failed: {}", e));
@@ -31,11 +75,12 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
     }
     
     // Determine model to use
-    let model_name = if let Some(ref model) = cmd.model {
+    let mut model_name = if let Some(model) = cmd.model.as_ref().or(config.default_model.as_ref()) {
         model.clone()
     } else {
         // List loaded models and prompt user to select
-        let loaded_models = client.loaded_models().await
+        // Stable ordering keeps the numbered list consistent between runs
+        let loaded_models = timed(&output, "loaded_models", fetch_loaded_models(&client, config.retries)).await
             .context("Failed to get loaded models")?;
         
         if loaded_models.is_empty() {
@@ -64,7 +109,7 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
             config: None,
         };
         
-        match client.load_model(load_request).await {
+        match timed(&output, "load_model", client.load_model(load_request)).await {
             Ok(response) => {
                 if response.success {
                     output.success(&format!("Model {} is ready", model));
@@ -80,15 +125,32 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
         }
     }
     
+    // Context size is only needed to size an automatic token budget
+    let mut context_size = if cmd.max_tokens == MaxTokens::Auto {
+        let context_size = fetch_context_size(&client, &model_name).await;
+        match context_size {
+            Some(size) => output.debug(&format!("Model context size: {} tokens", size)),
+            None => output.debug(&format!(
+                "Model context size unknown, auto max_tokens falls back to {}",
+                AUTO_MAX_TOKENS_FALLBACK
+            )),
+        }
+        context_size
+    } else {
+        None
+    };
+    
+    output.debug(&format!("Sampling: {}", SamplingParams::resolve(&cmd).describe()));
+    
     // Single message mode
-    if let Some(input_message) = cmd.input {
+    if let Some(ref input_message) = single_message {
         let mut messages = vec![];
         
         // Add system prompt if provided
-        if let Some(system) = cmd.system {
+        if let Some(ref system) = cmd.system {
             messages.push(ChatMessage {
                 role: "system".to_string(),
-                content: system,
+                content: system.clone(),
                 name: None,
             });
         }
@@ -96,32 +158,129 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
         // Add user message
         messages.push(ChatMessage {
             role: "user".to_string(),
-            content: input_message,
+            content: input_message.clone(),
             name: None,
         });
         
-        let request = ChatCompletionRequest {
-            model: model_name,
-            messages,
-            temperature: Some(cmd.temperature),
-            max_tokens: Some(cmd.max_tokens),
-            stream: Some(cmd.stream),
-            top_p: None,
-            n: None,
-            stop: None,
-            presence_penalty: None,
-            frequency_penalty: None,
-            logit_bias: None,
-            seed: None,
-            user: None,
+        // Seed the start of the reply so the model continues from it
+        if let Some(ref prefix) = cmd.assistant_prefix {
+            messages.push(assistant_prefill(prefix));
+        }
+        
+        let request = build_request(&cmd, &model_name, messages, context_size);
+        if cmd.max_tokens == MaxTokens::Auto {
+            output.debug(&format!("Auto max_tokens: {}", request.max_tokens.unwrap_or_default()));
+        }
+        
+        let structured = matches!(config.output_format.as_str(), "json" | "yaml");
+        
+        // A custom template bypasses the server's chat formatting entirely
+        if let Some(ref template) = chat_template {
+            let spinner = if structured {
+                ProgressBar::hidden()
+            } else {
+                spinner(&output, "{spinner:.green} Generating response [{elapsed}]")
+            };
+            let result = timed(&output, "completion", client.completion(template.completion_request(&request))).await;
+            spinner.finish_and_clear();
+            
+            match result {
+                Ok(response) => match response.choices.first() {
+                    Some(choice) => {
+                        let content = with_assistant_prefix(cmd.assistant_prefix.as_deref(), &choice.text);
+                        if structured {
+                            output.print(&serde_json::json!({ "model": model_name, "content": content }))?;
+                        } else {
+                            output.info("Response:");
+                            println!("{}", content);
+                        }
+                    }
+                    None => output.warning("No response generated"),
+                },
+                Err(e) => output.error(&format!("Completion failed: {}", e)),
+            }
+            
+            return Ok(());
+        }
+        
+        // Structured output and strict JSON need the whole reply before anything can be printed
+        if cmd.stream && !cmd.strict_json && !structured {
+            output.info("Response:");
+            if let Some(ref prefix) = cmd.assistant_prefix {
+                print!("{}", prefix);
+            }
+            
+            match stream_reply(&stream_client, request, &output).await {
+                Ok(reply) if reply.cancelled => output.info("Response cancelled"),
+                Ok(reply) => {
+                    if reply.truncated {
+                        output.warning("Response truncated: the stream ended before the reply was complete");
+                    }
+                }
+                Err(e) => output.error(&format!("Chat completion failed: {}", e)),
+            }
+            
+            return Ok(());
+        }
+        
+        let spinner = if structured {
+            ProgressBar::hidden()
+        } else {
+            spinner(&output, "{spinner:.green} Generating response [{elapsed}]")
         };
+        let result = timed(&output, "chat_completion", client.chat_completion(request)).await;
+        spinner.finish_and_clear();
         
-        output.status("Generating response...");
-        match client.chat_completion(request).await {
+        match result {
             Ok(response) => {
-                if let Some(choice) = response.choices.first() {
-                    output.info("Response:");
-                    println!("{}", choice.message.content);
+                if !response.choices.is_empty() {
+                    let contents: Vec<String> = response.choices.iter()
+                        .map(|choice| with_assistant_prefix(cmd.assistant_prefix.as_deref(), &choice.message.content))
+                        .collect();
+                    
+                    if structured {
+                        let values = contents.into_iter()
+                            .map(|content| if cmd.strict_json {
+                                extract_strict_json(&content, json_schema.as_ref())
+                            } else {
+                                Ok(serde_json::Value::String(content))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        let usage = response.usage.as_ref().map(|usage| serde_json::json!({
+                            "prompt_tokens": usage.prompt_tokens,
+                            "completion_tokens": usage.completion_tokens,
+                            "total_tokens": usage.total_tokens,
+                        }));
+                        
+                        // A single reply keeps the original shape; -n adds every choice
+                        let mut result = serde_json::json!({
+                            "model": model_name,
+                            "content": values[0],
+                            "usage": usage,
+                        });
+                        if values.len() > 1 {
+                            result["choices"] = serde_json::Value::Array(values);
+                        }
+                        output.print(&result)?;
+                        return Ok(());
+                    }
+                    
+                    for (index, content) in contents.iter().enumerate() {
+                        if contents.len() > 1 {
+                            output.subheader(&format!("Choice {} of {}", index + 1, contents.len()));
+                        }
+                        
+                        if cmd.strict_json {
+                            let value = extract_strict_json(content, json_schema.as_ref())?;
+                            println!("{}", serde_json::to_string_pretty(&value)?);
+                        } else if cmd.render {
+                            output.info("Response:");
+                            output.markdown(content);
+                        } else {
+                            output.info("Response:");
+                            println!("{}", content);
+                        }
+                    }
                     
                     // Show usage statistics if available
                     if let Some(usage) = response.usage {
@@ -136,6 +295,9 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
             }
             Err(e) => {
                 output.error(&format!("Chat completion failed: {}", e));
+                if cmd.assistant_prefix.is_some() {
+                    output.warning("The server may not support assistant prefill; try again without --assistant-prefix");
+                }
             }
         }
         
@@ -148,54 +310,244 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
     output.info("Type '/help' for available commands");
     println!();
     
-    let mut conversation_history = vec![];
+    let mut session_usage = SessionUsage::default();
     
-    // Add system prompt if provided
-    if let Some(system) = cmd.system {
-        conversation_history.push(ChatMessage {
+    let mut conversation_history = match (cmd.load_history.as_ref(), session_path.as_ref()) {
+        (Some(path), _) => {
+            let history = load_conversation_history(path, config.chat.max_history_bytes)?;
+            output.info(&format!("Loaded {} message(s) from {}", history.len(), path));
+            history
+        }
+        (None, Some(path)) if path.exists() => {
+            let history = load_conversation_history(&path.to_string_lossy(), config.chat.max_history_bytes)?;
+            output.info(&format!("Resumed session '{}' ({} message(s))", cmd.session.as_deref().unwrap_or_default(), history.len()));
+            history
+        }
+        (None, Some(_)) => {
+            output.info(&format!("Starting new session '{}'", cmd.session.as_deref().unwrap_or_default()));
+            vec![]
+        }
+        (None, None) => vec![],
+    };
+    
+    // Add system prompt if provided (a loaded conversation keeps its own)
+    let has_system = conversation_history.iter().any(|msg| msg.role == "system");
+    if let Some(system) = cmd.system.as_ref().filter(|_| !has_system) {
+        conversation_history.insert(0, ChatMessage {
             role: "system".to_string(),
-            content: system,
+            content: system.clone(),
             name: None,
         });
         output.debug("System prompt added to conversation");
     }
     
+    let mut reader = PromptReader::spawn();
+    
     loop {
         // Get user input
-        print!("You: ");
-        io::stdout().flush().unwrap();
-        
-        let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => {},
+        let input = match reader.read_line("You: ").await {
+            Ok(Some(line)) => line,
+            Ok(None) => {
+                println!();
+                output.info("Goodbye!");
+                break;
+            }
             Err(e) => {
                 output.error(&format!("Failed to read input: {}", e));
                 break;
             }
-        }
+        };
         
-        let input = input.trim();
+        // A `"""` line starts a multi-line message, ended by another `"""` line
+        let (input, multiline) = if input.trim() == MULTILINE_MARKER {
+            let mut lines = Vec::new();
+            let mut closed = false;
+            
+            while let Ok(Some(line)) = reader.read_line("... ").await {
+                let line = line.trim_end_matches(['\r', '\n']);
+                if line.trim() == MULTILINE_MARKER {
+                    closed = true;
+                    break;
+                }
+                lines.push(line.to_string());
+            }
+            
+            if !closed {
+                println!();
+                output.info("Multi-line message discarded");
+                continue;
+            }
+            (lines.join("\n"), true)
+        } else {
+            (input.trim().to_string(), false)
+        };
         
-        // Handle special commands
-        if input.is_empty() {
+        if input.trim().is_empty() {
             continue;
         }
         
-        if input == "exit" || input == "quit" {
+        // Multi-line text is sent verbatim and never treated as a command
+        let command = if multiline { "" } else { input.as_str() };
+        
+        if command == "exit" || command == "quit" {
+            if cmd.summarize_on_exit {
+                summarize_on_exit(&client, &cmd, &model_name, context_size, &conversation_history, &output).await;
+            }
             output.info("Goodbye!");
             break;
         }
         
-        if input == "/help" {
+        if command == "/help" {
             println!("Available commands:");
             println!("  exit, quit  - End the conversation");
             println!("  /help       - Show this help");
             println!("  /clear      - Clear conversation history");
             println!("  /history    - Show conversation history");
+            println!("  /paste-clipboard - Send the clipboard contents as your message");
+            println!("  /regenerate - Resample the last assistant reply, or resend an unanswered message");
+            println!("  /model [id] - Show loaded models or switch to another model");
+            println!("  /system [text] - Show or replace the system prompt");
+            println!("  /tokens     - Show estimated and reported token usage");
+            println!("  /undo       - Remove the last question and reply, or an unanswered question");
+            println!("  \"\"\"         - Start or end a multi-line message");
+            println!("  /save <path> - Save the conversation to a file");
+            println!("  /load <path> - Replace the conversation with one saved to a file");
+            continue;
+        }
+        
+        if let Some(new_model) = slash_argument(command, "/model") {
+            if new_model.is_empty() {
+                match timed(&output, "loaded_models", fetch_loaded_models(&client, config.retries)).await {
+                    Ok(loaded_models) => {
+                        output.info(&format!("Active model: {}", model_name));
+                        for model in &loaded_models {
+                            let marker = if model.model_id == model_name { "*" } else { " " };
+                            println!("  {} {} ({})", marker, model.model_id, model.status);
+                        }
+                    }
+                    Err(e) => output.error(&format!("Failed to get loaded models: {}", e)),
+                }
+                continue;
+            }
+            
+            output.status(&format!("Loading model {}...", new_model));
+            let load_request = LoadModelRequest {
+                model_id: new_model.to_string(),
+                filename: None,
+                config: None,
+            };
+            
+            match timed(&output, "load_model", client.load_model(load_request)).await {
+                Ok(response) if response.success => {
+                    model_name = new_model.to_string();
+                    if cmd.max_tokens == MaxTokens::Auto {
+                        context_size = fetch_context_size(&client, &model_name).await;
+                    }
+                    output.success(&format!("Switched to model {}", model_name));
+                }
+                Ok(response) => output.error(&format!(
+                    "Failed to load model: {}; still using {}", response.message, model_name
+                )),
+                Err(e) => output.error(&format!("Error loading model: {}; still using {}", e, model_name)),
+            }
+            continue;
+        }
+        
+        if command == "/undo" {
+            let len = conversation_history.len();
+            let is_exchange = len >= 2
+                && conversation_history[len - 1].role == "assistant"
+                && conversation_history[len - 2].role == "user";
+            
+            if is_exchange {
+                conversation_history.truncate(len - 2);
+                output.info("Removed the last exchange");
+            } else if conversation_history.last().is_some_and(|msg| msg.role == "user") {
+                conversation_history.pop();
+                output.info("Removed your unanswered message");
+            } else {
+                output.warning("There is no complete exchange to undo");
+            }
+            continue;
+        }
+        
+        if command == "/tokens" {
+            output.info(&format!("Estimated conversation size: ~{} tokens", estimate_tokens(&conversation_history)));
+            for role in HISTORY_ROLES {
+                let messages: Vec<ChatMessage> = conversation_history.iter()
+                    .filter(|msg| msg.role == *role)
+                    .cloned()
+                    .collect();
+                if !messages.is_empty() {
+                    println!("  {:<10} {:>3} message(s)  ~{} tokens", role, messages.len(), estimate_tokens(&messages));
+                }
+            }
+            
+            if session_usage.replies > 0 {
+                output.info(&format!(
+                    "Server-reported usage over {} repl{}: {} prompt + {} completion = {} total",
+                    session_usage.replies,
+                    if session_usage.replies == 1 { "y" } else { "ies" },
+                    session_usage.prompt_tokens,
+                    session_usage.completion_tokens,
+                    session_usage.prompt_tokens + session_usage.completion_tokens
+                ));
+            } else {
+                output.info("No server-reported usage yet (streamed replies don't include usage)");
+            }
+            continue;
+        }
+        
+        if let Some(prompt) = slash_argument(command, "/system") {
+            let existing = conversation_history.iter_mut().find(|msg| msg.role == "system");
+            
+            match (existing, prompt.is_empty()) {
+                (Some(system), true) => println!("System prompt: {}", system.content),
+                (None, true) => output.info("No system prompt is set"),
+                (Some(system), false) => {
+                    system.content = prompt.to_string();
+                    output.info("System prompt updated");
+                }
+                (None, false) => {
+                    conversation_history.insert(0, ChatMessage {
+                        role: "system".to_string(),
+                        content: prompt.to_string(),
+                        name: None,
+                    });
+                    output.info("System prompt set");
+                }
+            }
             continue;
         }
         
-        if input == "/clear" {
+        if let Some(path) = slash_argument(command, "/save") {
+            if path.is_empty() {
+                output.warning("Usage: /save <path>");
+            } else {
+                match save_conversation_history(&conversation_history, path, cmd.overwrite, &output) {
+                    Ok(_) => output.success(&format!("Conversation saved to: {}", path)),
+                    Err(e) => output.error(&format!("Failed to save conversation: {}", e)),
+                }
+            }
+            continue;
+        }
+        
+        if let Some(path) = slash_argument(command, "/load") {
+            if path.is_empty() {
+                output.warning("Usage: /load <path>");
+            } else {
+                match load_conversation_history(path, config.chat.max_history_bytes) {
+                    Ok(history) => {
+                        output.success(&format!("Loaded {} message(s) from {}", history.len(), path));
+                        conversation_history = history;
+                    }
+                    Err(e) => output.error(&format!("Failed to load conversation: {}", e)),
+                }
+            }
+            continue;
+        }
+        
+        if command == "/clear" {
             // Keep system message if present
             let system_msg = conversation_history.iter()
                 .find(|msg| msg.role == "system")
@@ -208,7 +560,7 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
             continue;
         }
         
-        if input == "/history" {
+        if command == "/history" {
             output.info("Conversation history:");
             for (i, msg) in conversation_history.iter().enumerate() {
                 println!("  {}: {}: {}", i + 1, msg.role, msg.content);
@@ -216,71 +568,240 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
             continue;
         }
         
-        // Add user message to history
-        conversation_history.push(ChatMessage {
-            role: "user".to_string(),
-            content: input.to_string(),
-            name: None,
-        });
+        // Reply being regenerated, restored if the new attempt fails
+        let mut replaced = None;
+        // Resending a message whose reply failed; it stays in history either way
+        let mut resending = false;
         
-        // Create chat completion request
-        let request = ChatCompletionRequest {
-            model: model_name.clone(),
-            messages: conversation_history.clone(),
-            temperature: Some(cmd.temperature),
-            max_tokens: Some(cmd.max_tokens),
-            stream: Some(cmd.stream),
-            top_p: None,
-            n: None,
-            stop: None,
-            presence_penalty: None,
-            frequency_penalty: None,
-            logit_bias: None,
-            seed: None,
-            user: None,
-        };
+        if command == "/regenerate" {
+            if conversation_history.last().is_some_and(|msg| msg.role == "assistant") {
+                replaced = conversation_history.pop();
+                output.info("Regenerating the last reply");
+            } else if conversation_history.last().is_some_and(|msg| msg.role == "user") {
+                resending = true;
+                output.info("Resending the last message");
+            } else {
+                output.warning("There is no assistant reply to regenerate");
+                continue;
+            }
+        } else if conversation_history.last().is_some_and(|msg| msg.role == "user") {
+            // Two user turns in a row would leave the failed one unanswered in the history
+            output.warning(&format!("Your last message has no reply yet; {}", UNANSWERED_HINT));
+            continue;
+        } else {
+            // Use the clipboard contents as the message
+            let message = if command == "/paste-clipboard" {
+                match read_clipboard() {
+                    Ok(text) => {
+                        output.info(&format!("Pasted {} characters from clipboard", text.chars().count()));
+                        text
+                    }
+                    Err(e) => {
+                        output.error(&e.to_string());
+                        continue;
+                    }
+                }
+            } else {
+                input.clone()
+            };
+            
+            // Add user message to history
+            conversation_history.push(ChatMessage {
+                role: "user".to_string(),
+                content: message,
+                name: None,
+            });
+        }
+        
+        // Catch context overflow before the server rejects the request
+        if let Some(limit) = cmd.context_limit {
+            let estimated = estimate_tokens(&conversation_history);
+            if estimated > limit {
+                if cmd.auto_trim {
+                    let dropped = trim_history(&mut conversation_history, limit);
+                    output.warning(&format!(
+                        "Conversation is ~{} tokens, over the {} token limit; dropped {} oldest message(s)",
+                        estimated, limit, dropped
+                    ));
+                } else {
+                    output.warning(&format!(
+                        "Conversation is ~{} tokens, over the {} token limit; use /clear or --auto-trim",
+                        estimated, limit
+                    ));
+                }
+            }
+        }
+        
+        // Create chat completion request, seeding the reply if a prefix was given
+        let mut messages = conversation_history.clone();
+        if let Some(ref prefix) = cmd.assistant_prefix {
+            messages.push(assistant_prefill(prefix));
+        }
+        let request = build_request(&cmd, &model_name, messages, context_size);
+        if cmd.max_tokens == MaxTokens::Auto {
+            output.debug(&format!("Auto max_tokens: {}", request.max_tokens.unwrap_or_default()));
+        }
         
         // Send request and get response
         print!("Assistant: ");
         io::stdout().flush().unwrap();
         
-        match client.chat_completion(request).await {
+        if cmd.stream {
+            if let Some(ref prefix) = cmd.assistant_prefix {
+                print!("{}", prefix);
+            }
+            
+            match stream_reply(&stream_client, request, &output).await {
+                Ok(reply) if reply.cancelled => {
+                    // Drop the unanswered turn so the history stays a clean exchange
+                    if !resending {
+                        restore_turn(&mut conversation_history, replaced);
+                    }
+                    output.info("Response cancelled; your last message was not kept in the conversation");
+                }
+                Ok(reply) => {
+                    if reply.truncated {
+                        output.warning("Response truncated: the stream ended before the reply was complete");
+                    }
+                    conversation_history.push(ChatMessage {
+                        role: "assistant".to_string(),
+                        content: with_assistant_prefix(cmd.assistant_prefix.as_deref(), &reply.content),
+                        name: None,
+                    });
+                }
+                Err(e) => {
+                    if let Some(previous) = replaced {
+                        conversation_history.push(previous);
+                    }
+                    output.error(&format!("Chat completion failed: {}", e));
+                    output.info(&format!("Your message was kept; {}, or type 'exit' to quit", UNANSWERED_HINT));
+                }
+            }
+            
+            if cmd.autosave {
+                autosave_session(&conversation_history, session_path.as_deref(), &output);
+            }
+            
+            println!();
+            continue;
+        }
+        
+        if let Some(ref template) = chat_template {
+            let completion = template.completion_request(&request);
+            let spinner = spinner(&output, "{spinner:.green} Generating response [{elapsed}]");
+            let result = timed(&output, "completion", retry_transient(CHAT_ATTEMPTS, &output, || client.completion(completion.clone()))).await;
+            spinner.finish_and_clear();
+            
+            match result {
+                Ok(response) => match response.choices.first() {
+                    Some(choice) => {
+                        let content = with_assistant_prefix(cmd.assistant_prefix.as_deref(), &choice.text);
+                        println!("{}", content);
+                        conversation_history.push(ChatMessage {
+                            role: "assistant".to_string(),
+                            content,
+                            name: None,
+                        });
+                    }
+                    None => output.warning("No response generated"),
+                },
+                Err(e) => {
+                    if let Some(previous) = replaced {
+                        conversation_history.push(previous);
+                    }
+                    output.error(&format!("Completion failed: {}", e));
+                    output.info(&format!("Your message was kept; {}, or type 'exit' to quit", UNANSWERED_HINT));
+                }
+            }
+            
+            if cmd.autosave {
+                autosave_session(&conversation_history, session_path.as_deref(), &output);
+            }
+            
+            println!();
+            continue;
+        }
+        
+        // A network blip shouldn't cost the user their message
+        let spinner = spinner(&output, "{spinner:.green} Generating response [{elapsed}]");
+        let result = timed(&output, "chat_completion", retry_transient(CHAT_ATTEMPTS, &output, || client.chat_completion(request.clone()))).await;
+        spinner.finish_and_clear();
+        
+        match result {
             Ok(response) => {
-                if let Some(choice) = response.choices.first() {
-                    println!("{}", choice.message.content);
+                if !response.choices.is_empty() {
+                    let mut contents: Vec<String> = response.choices.iter()
+                        .map(|choice| with_assistant_prefix(cmd.assistant_prefix.as_deref(), &choice.message.content))
+                        .collect();
+                    
+                    for (index, content) in contents.iter().enumerate() {
+                        if contents.len() > 1 {
+                            println!();
+                            output.subheader(&format!("Choice {} of {}", index + 1, contents.len()));
+                        }
+                        
+                        if cmd.render {
+                            println!();
+                            output.markdown(content);
+                        } else {
+                            println!("{}", content);
+                        }
+                    }
+                    
+                    // Only one candidate can continue the conversation
+                    let kept = choose_reply(contents.len())?;
+                    let content = contents.swap_remove(kept);
                     
                     // Add assistant response to history
                     conversation_history.push(ChatMessage {
                         role: "assistant".to_string(),
-                        content: choice.message.content.clone(),
+                        content,
                         name: None,
                     });
                     
-                    // Show token usage in verbose mode
-                    if verbose {
-                        if let Some(usage) = response.usage {
-                            output.debug(&format!(
-                                "Tokens: {} prompt + {} completion = {} total",
-                                usage.prompt_tokens, usage.completion_tokens, usage.total_tokens
-                            ));
-                        }
+                    if let Some(usage) = response.usage {
+                        session_usage.record(usage.prompt_tokens as u64, usage.completion_tokens as u64);
+                        
+                        // Token usage is only shown with -v
+                        output.debug(&format!(
+                            "Tokens: {} prompt + {} completion = {} total",
+                            usage.prompt_tokens, usage.completion_tokens, usage.total_tokens
+                        ));
                     }
                 } else {
                     output.warning("No response generated");
                 }
             }
             Err(e) => {
+                if let Some(previous) = replaced {
+                    conversation_history.push(previous);
+                }
                 output.error(&format!("Chat completion failed: {}", e));
-                output.info("You can continue the conversation or type 'exit' to quit");
+                if cmd.assistant_prefix.is_some() {
+                    output.warning("The server may not support assistant prefill; try again without --assistant-prefix");
+                }
+                output.info(&format!("Your message was kept; {}, or type 'exit' to quit", UNANSWERED_HINT));
             }
         }
         
+        if cmd.autosave {
+            autosave_session(&conversation_history, session_path.as_deref(), &output);
+        }
+        
         println!(); // Add blank line for readability
     }
     
+    // Save the session so the next `--session` run resumes here
+    if let Some(ref path) = session_path {
+        match save_session(&conversation_history, path, &output) {
+            Ok(_) => output.success(&format!("Session saved to: {}", path.display())),
+            Err(e) => output.error(&format!("Failed to save session: {}", e)),
+        }
+    }
+    
     // Save conversation history if requested
-    if let Some(save_path) = cmd.save_history {
-        match save_conversation_history(&conversation_history, &save_path) {
+    if let Some(ref save_path) = cmd.save_history {
+        match save_conversation_history(&conversation_history, save_path, cmd.overwrite, &output) {
             Ok(_) => output.success(&format!("Conversation saved to: {}", save_path)),
             Err(e) => output.error(&format!("Failed to save conversation: {}", e)),
         }
@@ -289,12 +810,871 @@ pub async fn handle(cmd: ChatCommand, config: &CliConfig, verbose: bool) -> Resu
     Ok(())
 }
 
-fn save_conversation_history(history: &[ChatMessage], path: &str) -> Result<()> {
-    let json = serde_json::to_string_pretty(history)
-        .context("Failed to serialize conversation history")?;
+/// Token usage reported by the server, accumulated over an interactive session
+#[derive(Debug, Default)]
+struct SessionUsage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    replies: u32,
+}
+
+impl SessionUsage {
+    fn record(&mut self, prompt_tokens: u64, completion_tokens: u64) {
+        self.prompt_tokens += prompt_tokens;
+        self.completion_tokens += completion_tokens;
+        self.replies += 1;
+    }
+}
+
+/// Line that opens and closes a multi-line message in interactive chat
+const MULTILINE_MARKER: &str = "\"\"\"";
+
+/// Ask which of several candidate replies to keep in the history; the first
+/// is kept when there is only one or no terminal to prompt on
+fn choose_reply(count: usize) -> Result<usize> {
+    if count <= 1 || !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        return Ok(0);
+    }
+    
+    let items: Vec<String> = (1..=count).map(|index| format!("Choice {}", index)).collect();
+    Ok(dialoguer::Select::new()
+        .with_prompt("Keep which reply in the conversation?")
+        .items(&items)
+        .default(0)
+        .interact()?)
+}
+
+/// Attempts for a chat completion (or opening its stream) that fails with a transient error
+const CHAT_ATTEMPTS: u32 = 3;
+
+/// How to get past a message whose reply failed
+const UNANSWERED_HINT: &str = "use /regenerate to resend it or /undo to drop it";
+
+/// Health check attempts before chat gives up on the server
+const HEALTH_CHECK_ATTEMPTS: u32 = 3;
+
+/// Pause between chat health check attempts
+const HEALTH_CHECK_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Check server health with a spinner, retrying a few times so that launching
+/// the server and chat back-to-back doesn't fail while the server starts
+async fn check_chat_health(
+    client: &LmoClient,
+    output: &OutputFormatter,
+) -> Result<lmoclient::models::HealthResponse, lmoclient::ClientError> {
+    let spinner = spinner(output, "{spinner:.green} Checking server health {msg}");
+    
+    let mut attempt = 1;
+    let result = loop {
+        match timed(output, "health", client.health()).await {
+            Err(e) if attempt < HEALTH_CHECK_ATTEMPTS => {
+                debug!("Health check attempt {}/{} failed: {}", attempt, HEALTH_CHECK_ATTEMPTS, e);
+                attempt += 1;
+                spinner.set_message(format!("(attempt {}/{})", attempt, HEALTH_CHECK_ATTEMPTS));
+                tokio::time::sleep(HEALTH_CHECK_RETRY_DELAY).await;
+            }
+            result => break result,
+        }
+    };
+    
+    spinner.finish_and_clear();
+    result
+}
+
+/// Reads interactive input on one thread kept for the whole session. A prompt
+/// abandoned on Ctrl+C leaves its read pending, and the line it eventually
+/// returns goes to the next prompt instead of being lost or blocking exit.
+struct PromptReader {
+    requests: std::sync::mpsc::Sender<()>,
+    lines: tokio::sync::mpsc::UnboundedReceiver<io::Result<Option<String>>>,
+    pending: bool,
+}
+
+impl PromptReader {
+    fn spawn() -> Self {
+        let (requests, wanted) = std::sync::mpsc::channel::<()>();
+        let (sender, lines) = tokio::sync::mpsc::unbounded_channel();
+        
+        // Only read when a prompt asks, so other prompts (e.g. reply selection) get the terminal
+        std::thread::spawn(move || {
+            while wanted.recv().is_ok() {
+                let mut line = String::new();
+                let read = io::stdin().lock().read_line(&mut line).map(|bytes| (bytes > 0).then_some(line));
+                if sender.send(read).is_err() {
+                    break;
+                }
+            }
+        });
+        
+        Self { requests, lines, pending: false }
+    }
+    
+    /// Print a prompt and read one line of input. Returns `None` on Ctrl+C or end
+    /// of input; streaming replaces the default Ctrl+C handler, so it is caught here.
+    async fn read_line(&mut self, prompt: &str) -> Result<Option<String>> {
+        print!("{}", prompt);
+        io::stdout().flush()?;
+        
+        if !self.pending {
+            self.requests.send(()).context("Input reader stopped")?;
+            self.pending = true;
+        }
+        
+        tokio::select! {
+            read = self.lines.recv() => {
+                self.pending = false;
+                Ok(read.transpose()?.flatten())
+            }
+            _ = signal::ctrl_c() => Ok(None),
+        }
+    }
+}
+
+/// Undo a turn that produced no reply: put back the reply being regenerated,
+/// or drop the unanswered user message
+fn restore_turn(history: &mut Vec<ChatMessage>, replaced: Option<ChatMessage>) {
+    match replaced {
+        Some(previous) => history.push(previous),
+        None => {
+            history.pop();
+        }
+    }
+}
+
+/// The (trimmed) argument of a slash command such as `/save <path>`, or `None` if
+/// `input` is a different command
+fn slash_argument<'a>(input: &'a str, command: &str) -> Option<&'a str> {
+    let rest = input.strip_prefix(command)?;
+    
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim())
+    } else {
+        None
+    }
+}
+
+/// Placeholders a --template file may use
+const TEMPLATE_PLACEHOLDERS: &[&str] = &["{system}", "{messages}", "{user}"];
+
+/// Prompt template given with --template, for models whose chat format the
+/// server doesn't apply
+struct ChatTemplate {
+    text: String,
+}
+
+impl ChatTemplate {
+    /// Read a template, requiring somewhere to put the conversation
+    fn load(path: &str) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read template {}", path))?;
+        
+        if !text.contains("{user}") && !text.contains("{messages}") {
+            return Err(CliError::InvalidInput(format!(
+                "Template {} needs a {{user}} or {{messages}} placeholder",
+                path
+            )).into());
+        }
+        
+        Ok(Self { text })
+    }
+    
+    /// Format a conversation as one prompt. When the template has `{user}`, the
+    /// final user message goes there and `{messages}` holds the turns before it;
+    /// a trailing assistant message (a prefill) is appended to the prompt.
+    fn render(&self, messages: &[ChatMessage]) -> String {
+        let (messages, prefill) = match messages.split_last() {
+            Some((last, rest)) if last.role == "assistant" => (rest, Some(last.content.as_str())),
+            _ => (messages, None),
+        };
+        
+        let system = messages.iter()
+            .filter(|msg| msg.role == "system")
+            .map(|msg| msg.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut turns: Vec<&ChatMessage> = messages.iter().filter(|msg| msg.role != "system").collect();
+        
+        let user = match turns.last() {
+            Some(last) if last.role == "user" && self.text.contains("{user}") => {
+                let content = last.content.as_str();
+                turns.pop();
+                content
+            }
+            _ => "",
+        };
+        
+        let history = turns.iter()
+            .map(|msg| match msg.role.as_str() {
+                "user" => format!("User: {}", msg.content),
+                role => format!("{}: {}", role_label(role), msg.content),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        
+        // Substitute in one pass so placeholder-like text in messages is left alone
+        let mut prompt = String::new();
+        let mut rest = self.text.as_str();
+        while let Some(start) = rest.find('{') {
+            prompt.push_str(&rest[..start]);
+            let tail = &rest[start..];
+            
+            let (value, consumed) = match TEMPLATE_PLACEHOLDERS.iter().find(|placeholder| tail.starts_with(**placeholder)) {
+                Some(&"{system}") => (system.as_str(), "{system}".len()),
+                Some(&"{messages}") => (history.as_str(), "{messages}".len()),
+                Some(&"{user}") => (user, "{user}".len()),
+                _ => ("{", 1),
+            };
+            prompt.push_str(value);
+            rest = &tail[consumed..];
+        }
+        prompt.push_str(rest);
+        
+        if let Some(prefill) = prefill {
+            prompt.push_str(prefill);
+        }
+        
+        prompt
+    }
+    
+    /// Raw completion request carrying the chat request's sampling settings
+    fn completion_request(&self, request: &ChatCompletionRequest) -> CompletionRequest {
+        CompletionRequest {
+            model: request.model.clone(),
+            prompt: self.render(&request.messages),
+            max_tokens: request.max_tokens,
+            temperature: request.temperature,
+            stop: request.stop.clone(),
+            stream: false,
+        }
+    }
+}
+
+/// Read and compile a JSON Schema file for `--json-schema`
+fn load_json_schema(path: &str) -> Result<jsonschema::Validator> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read JSON schema from {}", path))?;
+    let schema: serde_json::Value = serde_json::from_str(&text)
+        .with_context(|| format!("{} is not valid JSON", path))?;
+    
+    jsonschema::validator_for(&schema)
+        .map_err(|e| CliError::InvalidInput(format!("Invalid JSON schema in {}: {}", path, e)).into())
+}
+
+/// Pull the first JSON object or array out of a reply, ignoring markdown fences
+/// and surrounding prose, and check it against the schema if one was given
+fn extract_strict_json(content: &str, schema: Option<&jsonschema::Validator>) -> Result<serde_json::Value> {
+    let text = strip_code_fences(content);
+    
+    // The first start position that parses wins; trailing text after the value is ignored
+    let value = text.char_indices()
+        .filter(|(_, c)| matches!(c, '{' | '['))
+        .find_map(|(i, _)| {
+            serde_json::Deserializer::from_str(&text[i..])
+                .into_iter::<serde_json::Value>()
+                .next()
+                .and_then(|parsed| parsed.ok())
+        })
+        .ok_or_else(|| CliError::ChatError(format!("Response contains no valid JSON. Raw response:\n{}", content)))?;
+    
+    if let Some(validator) = schema {
+        let errors: Vec<String> = validator.iter_errors(&value)
+            .map(|e| format!("  {} at '{}'", e, e.instance_path))
+            .collect();
+        
+        if !errors.is_empty() {
+            return Err(CliError::ChatError(format!(
+                "Response JSON does not match the schema:\n{}\nRaw response:\n{}",
+                errors.join("\n"),
+                content
+            )).into());
+        }
+    }
+    
+    Ok(value)
+}
+
+/// Return the body of the first ``` fenced block, or the text unchanged if there is none
+fn strip_code_fences(content: &str) -> &str {
+    let Some(start) = content.find("```") else {
+        return content;
+    };
+    
+    // Skip the info string (e.g. "json") on the opening fence line
+    let body = &content[start + 3..];
+    let body = body.find('\n').map_or(body, |newline| &body[newline + 1..]);
+    
+    match body.find("```") {
+        Some(end) => &body[..end],
+        None => body,
+    }
+}
+
+/// Roles a saved conversation may contain
+const HISTORY_ROLES: &[&str] = &["system", "user", "assistant", "tool"];
+
+/// Load a conversation history previously written by `save_conversation_history`,
+/// refusing files over `max_bytes` and anything that isn't a list of chat messages
+pub fn load_conversation_history(path: &str, max_bytes: u64) -> Result<Vec<ChatMessage>> {
+    let size = std::fs::metadata(path)
+        .with_context(|| format!("Failed to read conversation history from {}", path))?
+        .len();
+    
+    if size > max_bytes {
+        return Err(CliError::InvalidInput(format!(
+            "{} is {} bytes, larger than the {} byte limit for conversation files (see chat.max_history_bytes)",
+            path, size, max_bytes
+        )).into());
+    }
+    
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read conversation history from {}", path))?;
+    let json = String::from_utf8(bytes)
+        .map_err(|_| CliError::InvalidInput(format!("{} is not valid UTF-8", path)))?;
+    
+    let history = parse_history(&json)
+        .map_err(|e| CliError::InvalidInput(format!("{} is not a saved conversation history: {}", path, e)))?;
+    
+    Ok(history)
+}
+
+/// Parse a conversation and check every message has a recognized role
+fn parse_history(json: &str) -> Result<Vec<ChatMessage>, String> {
+    let history: Vec<ChatMessage> = serde_json::from_str(json)
+        .map_err(|e| e.to_string())?;
+    
+    if let Some((index, msg)) = history.iter().enumerate().find(|(_, msg)| !HISTORY_ROLES.contains(&msg.role.as_str())) {
+        return Err(format!("message #{} has unrecognized role '{}'", index + 1, msg.role));
+    }
+    
+    Ok(history)
+}
+
+/// Refuse to save over an existing file that isn't a transcript in the format its
+/// extension names (so `/save` can be repeated), unless overwriting
+fn check_history_target(path: &str, overwrite: bool) -> Result<()> {
+    let target = std::path::Path::new(path);
+    if overwrite || !target.exists() {
+        return Ok(());
+    }
+    
+    if target.is_dir() {
+        return Err(CliError::InvalidInput(format!("{} is a directory", path)).into());
+    }
+    
+    let is_history = TranscriptFormat::from_path(path)
+        .zip(std::fs::read_to_string(target).ok())
+        .is_some_and(|(format, contents)| format.is_transcript(&contents));
+    
+    if !is_history {
+        return Err(CliError::InvalidInput(format!(
+            "{} already exists and is not a conversation history; pass --overwrite to replace it",
+            path
+        )).into());
+    }
+    
+    Ok(())
+}
+
+/// Directory holding named chat sessions (e.g. ~/.local/share/lmo/sessions)
+fn sessions_dir() -> Result<PathBuf> {
+    dirs::data_dir()
+        .map(|dir| dir.join("lmo").join("sessions"))
+        .ok_or_else(|| CliError::ConfigError("Could not determine the data directory for chat sessions".to_string()).into())
+}
+
+/// Path of a named session; names are plain file stems so they can't escape the directory
+fn session_file(name: &str) -> Result<PathBuf> {
+    let valid = !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !name.starts_with('.');
+    
+    if !valid {
+        return Err(CliError::InvalidInput(format!(
+            "Invalid session name '{}': use letters, digits, '-', '_' and '.'",
+            name
+        )).into());
+    }
+    
+    Ok(sessions_dir()?.join(format!("{}.json", name)))
+}
+
+/// Save a session, creating the sessions directory on first use
+fn save_session(history: &[ChatMessage], path: &Path, output: &OutputFormatter) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    
+    save_conversation_history(history, &path.to_string_lossy(), false, output)
+}
+
+/// Save the session after a completed turn; a failure only warns so the chat can go on
+fn autosave_session(history: &[ChatMessage], path: Option<&Path>, output: &OutputFormatter) {
+    if let Some(path) = path {
+        if let Err(e) = save_session(history, path, output) {
+            output.warning(&format!("Failed to autosave session: {}", e));
+        }
+    }
+}
+
+/// Print saved sessions, most recently used first
+fn list_sessions(config: &CliConfig, output: &OutputFormatter) -> Result<()> {
+    let dir = sessions_dir()?;
+    let mut sessions: Vec<(String, DateTime<Local>)> = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                    return None;
+                }
+                let name = path.file_stem()?.to_string_lossy().into_owned();
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                Some((name, DateTime::<Local>::from(modified)))
+            })
+            .collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+        Err(e) => return Err(CliError::IoError(format!("Failed to read {}: {}", dir.display(), e)).into()),
+    };
+    sessions.sort_by(|a, b| b.1.cmp(&a.1));
+    
+    if matches!(config.output_format.as_str(), "json" | "yaml") {
+        let entries: Vec<serde_json::Value> = sessions.iter()
+            .map(|(name, modified)| serde_json::json!({ "name": name, "modified": modified.to_rfc3339() }))
+            .collect();
+        return output.print(&entries);
+    }
+    
+    if sessions.is_empty() {
+        output.info("No saved sessions. Start one with `lmo chat --session <name>`.");
+        return Ok(());
+    }
+    
+    output.header(&format!("Chat Sessions ({})", sessions.len()));
+    eprintln!();
+    
+    for (name, modified) in &sessions {
+        println!("{:<32} {}", name, modified.format("%Y-%m-%d %H:%M"));
+    }
+    
+    Ok(())
+}
+
+/// File formats a conversation can be saved in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TranscriptFormat {
+    /// Round-trippable `Vec<ChatMessage>`, readable by `--load-history`
+    Json,
+    /// Readable transcript with bold role headers
+    Markdown,
+    /// Plain role-prefixed lines
+    Text,
+}
+
+impl TranscriptFormat {
+    /// Pick a format from the file extension; `None` if it isn't recognized
+    fn from_path(path: &str) -> Option<Self> {
+        let extension = std::path::Path::new(path).extension()?.to_str()?.to_lowercase();
+        
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "md" | "markdown" => Some(Self::Markdown),
+            "txt" => Some(Self::Text),
+            _ => None,
+        }
+    }
+    
+    /// Whether existing file contents look like a transcript saved in this format
+    fn is_transcript(self, contents: &str) -> bool {
+        let first_line = contents.lines().next().unwrap_or_default();
+        
+        match self {
+            Self::Json => parse_history(contents).is_ok(),
+            Self::Markdown => HISTORY_ROLES.iter()
+                .any(|role| first_line == format!("**{}:**", role_label(role))),
+            Self::Text => HISTORY_ROLES.iter()
+                .any(|role| first_line.starts_with(&format!("{}: ", role_label(role)))),
+        }
+    }
+    
+    fn render(self, history: &[ChatMessage]) -> Result<String> {
+        let rendered = match self {
+            Self::Json => serde_json::to_string_pretty(history)
+                .context("Failed to serialize conversation history")?,
+            Self::Markdown => history.iter()
+                .map(|msg| format!("**{}:**\n\n{}\n", role_label(&msg.role), msg.content))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Text => history.iter()
+                .map(|msg| format!("{}: {}\n", role_label(&msg.role), msg.content))
+                .collect(),
+        };
+        
+        Ok(rendered)
+    }
+}
+
+/// Display name for a message role in transcripts
+fn role_label(role: &str) -> &str {
+    match role {
+        "user" => "You",
+        "assistant" => "Assistant",
+        "system" => "System",
+        "tool" => "Tool",
+        other => other,
+    }
+}
+
+/// Save a conversation in the format implied by the file extension (JSON by default)
+fn save_conversation_history(history: &[ChatMessage], path: &str, overwrite: bool, output: &OutputFormatter) -> Result<()> {
+    check_history_target(path, overwrite)?;
+    
+    let format = TranscriptFormat::from_path(path).unwrap_or_else(|| {
+        output.warning(&format!("Unrecognized extension for {}; saving as JSON", path));
+        TranscriptFormat::Json
+    });
     
-    std::fs::write(path, json)
+    std::fs::write(path, format.render(history)?)
         .context("Failed to write conversation history to file")?;
     
     Ok(())
+}
+
+/// Minimum number of user/assistant messages before a session is worth summarizing
+const MIN_MESSAGES_TO_SUMMARIZE: usize = 4;
+
+/// Instruction sent to the model when summarizing a conversation
+const SUMMARY_INSTRUCTION: &str =
+    "Summarize this conversation in one concise paragraph, covering the main topics and conclusions.";
+
+/// Token budget used by `--max-tokens auto` when the context size is unknown
+const AUTO_MAX_TOKENS_FALLBACK: u32 = 1000;
+
+/// Tokens held back from the remaining context to absorb estimation error
+const AUTO_MAX_TOKENS_MARGIN: u32 = 64;
+
+/// Build a chat completion request using the sampling settings from the command line
+fn build_request(
+    cmd: &ChatCommand,
+    model: &str,
+    messages: Vec<ChatMessage>,
+    context_size: Option<u32>,
+) -> ChatCompletionRequest {
+    let max_tokens = resolve_max_tokens(cmd.max_tokens, context_size, &messages);
+    let sampling = SamplingParams::resolve(cmd);
+    
+    ChatCompletionRequest {
+        model: model.to_string(),
+        messages,
+        temperature: Some(sampling.temperature),
+        max_tokens: Some(max_tokens),
+        stream: Some(cmd.stream),
+        top_p: sampling.top_p,
+        n: (cmd.n > 1).then_some(cmd.n),
+        stop: (!cmd.stop.is_empty()).then(|| cmd.stop.clone()),
+        presence_penalty: sampling.presence_penalty,
+        frequency_penalty: sampling.frequency_penalty,
+        logit_bias: None,
+        seed: sampling.seed,
+        user: None,
+    }
+}
+
+/// Temperature used when neither --temperature nor --preset is given
+const DEFAULT_TEMPERATURE: f32 = 0.7;
+
+/// Seed used by the deterministic preset
+const DETERMINISTIC_SEED: u64 = 42;
+
+/// Sampling parameters sent with each request
+#[derive(Debug, Clone, Copy)]
+struct SamplingParams {
+    temperature: f32,
+    top_p: Option<f32>,
+    presence_penalty: Option<f32>,
+    frequency_penalty: Option<f32>,
+    seed: Option<u64>,
+}
+
+impl SamplingParams {
+    /// Values bundled with a preset
+    fn preset(preset: SamplingPreset) -> Self {
+        match preset {
+            SamplingPreset::Creative => Self {
+                temperature: 1.0,
+                top_p: Some(0.95),
+                presence_penalty: Some(0.6),
+                frequency_penalty: Some(0.3),
+                seed: None,
+            },
+            SamplingPreset::Balanced => Self {
+                temperature: 0.7,
+                top_p: Some(0.9),
+                presence_penalty: None,
+                frequency_penalty: None,
+                seed: None,
+            },
+            SamplingPreset::Precise => Self {
+                temperature: 0.1,
+                top_p: Some(0.9),
+                presence_penalty: None,
+                frequency_penalty: None,
+                seed: None,
+            },
+            SamplingPreset::Deterministic => Self {
+                temperature: 0.0,
+                top_p: Some(1.0),
+                presence_penalty: None,
+                frequency_penalty: None,
+                seed: Some(DETERMINISTIC_SEED),
+            },
+        }
+    }
+    
+    /// Preset values (if any) with individual flags applied on top
+    fn resolve(cmd: &ChatCommand) -> Self {
+        let base = match cmd.preset {
+            Some(preset) => Self::preset(preset),
+            None => Self {
+                temperature: DEFAULT_TEMPERATURE,
+                top_p: None,
+                presence_penalty: None,
+                frequency_penalty: None,
+                seed: None,
+            },
+        };
+        
+        Self {
+            temperature: cmd.temperature.unwrap_or(base.temperature),
+            top_p: cmd.top_p.or(base.top_p),
+            presence_penalty: cmd.presence_penalty.or(base.presence_penalty),
+            frequency_penalty: cmd.frequency_penalty.or(base.frequency_penalty),
+            seed: cmd.seed.or(base.seed),
+        }
+    }
+    
+    /// One-line description for verbose output
+    fn describe(&self) -> String {
+        let optional = |value: Option<f32>| value.map_or("default".to_string(), |v| v.to_string());
+        
+        format!(
+            "temperature {}, top_p {}, presence_penalty {}, frequency_penalty {}, seed {}",
+            self.temperature,
+            optional(self.top_p),
+            optional(self.presence_penalty),
+            optional(self.frequency_penalty),
+            self.seed.map_or("random".to_string(), |seed| seed.to_string())
+        )
+    }
+}
+
+/// Text assembled from a streamed reply
+struct StreamedReply {
+    /// Content received so far
+    content: String,
+    
+    /// The stream failed before completing, so the content may be incomplete
+    truncated: bool,
+    
+    /// The user pressed Ctrl+C before the reply finished
+    cancelled: bool,
+}
+
+/// Stream a chat completion, printing each delta to stdout as it arrives.
+///
+/// Malformed chunks are skipped (and logged at debug level) instead of aborting
+/// the turn. Opening the stream is retried on transient errors; a transport-level
+/// failure after that ends the stream early but keeps the text received so far,
+/// marked as truncated. Ctrl+C stops the stream and marks the reply as cancelled.
+async fn stream_reply(
+    client: &LmoClient,
+    request: ChatCompletionRequest,
+    output: &OutputFormatter,
+) -> Result<StreamedReply> {
+    let chunk_stream = timed(output, "chat_completion_stream", retry_transient(CHAT_ATTEMPTS, output, || client.chat_completion_stream(request.clone()))).await?;
+    let mut stream = Box::pin(chunk_stream.into_stream());
+    
+    let ctrl_c = signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    
+    let mut content = String::new();
+    let mut skipped_chunks = 0;
+    
+    loop {
+        // Dropping the stream closes the connection, which stops generation server-side
+        let chunk_result = tokio::select! {
+            next = stream.next() => match next {
+                Some(chunk_result) => chunk_result,
+                None => break,
+            },
+            _ = &mut ctrl_c => {
+                println!();
+                return Ok(StreamedReply { content, truncated: true, cancelled: true });
+            }
+        };
+        
+        match chunk_result {
+            Ok(chunk) => {
+                let delta = chunk.choices.first()
+                    .and_then(|choice| choice.delta.content.as_deref());
+                
+                if let Some(delta) = delta {
+                    print!("{}", delta);
+                    io::stdout().flush()?;
+                    content.push_str(delta);
+                }
+            }
+            Err(e) if is_malformed_chunk_error(&e) => {
+                skipped_chunks += 1;
+                debug!("Skipping malformed stream chunk: {}", e);
+            }
+            Err(e) => {
+                println!();
+                if content.is_empty() {
+                    return Err(e.into());
+                }
+                output.warning(&format!("Stream interrupted: {}", e));
+                return Ok(StreamedReply { content, truncated: true, cancelled: false });
+            }
+        }
+    }
+    
+    println!();
+    if skipped_chunks > 0 {
+        debug!("Skipped {} malformed stream chunk(s)", skipped_chunks);
+    }
+    
+    Ok(StreamedReply { content, truncated: false, cancelled: false })
+}
+
+/// Whether a stream error comes from an unparseable chunk rather than the transport
+fn is_malformed_chunk_error(error: &lmoclient::ClientError) -> bool {
+    matches!(error, lmoclient::ClientError::SerializationError(_))
+}
+
+/// Partial assistant message the model should continue from
+fn assistant_prefill(prefix: &str) -> ChatMessage {
+    ChatMessage {
+        role: "assistant".to_string(),
+        content: prefix.to_string(),
+        name: None,
+    }
+}
+
+/// Join the assistant prefix and the generated continuation into the full reply
+fn with_assistant_prefix(prefix: Option<&str>, content: &str) -> String {
+    match prefix {
+        // Some servers echo the prefill back, so don't duplicate it
+        Some(prefix) if !content.starts_with(prefix) => format!("{}{}", prefix, content),
+        _ => content.to_string(),
+    }
+}
+
+/// Turn the requested token budget into a concrete max_tokens value
+fn resolve_max_tokens(max_tokens: MaxTokens, context_size: Option<u32>, messages: &[ChatMessage]) -> u32 {
+    match max_tokens {
+        MaxTokens::Fixed(tokens) => tokens,
+        MaxTokens::Auto => match context_size {
+            Some(context_size) => context_size
+                .saturating_sub(estimate_tokens(messages))
+                .saturating_sub(AUTO_MAX_TOKENS_MARGIN)
+                .max(1),
+            None => AUTO_MAX_TOKENS_FALLBACK,
+        },
+    }
+}
+
+/// Rough prompt token estimate (~4 characters per token plus per-message overhead)
+fn estimate_tokens(messages: &[ChatMessage]) -> u32 {
+    messages.iter()
+        .map(|msg| (msg.content.chars().count() as u32).div_ceil(4) + 4)
+        .sum()
+}
+
+/// Drop the oldest non-system messages until the estimate fits `limit`, always
+/// keeping the system prompt and the latest message. Returns how many were dropped.
+fn trim_history(history: &mut Vec<ChatMessage>, limit: u32) -> usize {
+    let mut dropped = 0;
+    
+    while estimate_tokens(history) > limit {
+        let oldest = history.iter()
+            .position(|msg| msg.role != "system")
+            .filter(|&index| index + 1 < history.len());
+        
+        match oldest {
+            Some(index) => {
+                history.remove(index);
+                dropped += 1;
+            }
+            None => break,
+        }
+    }
+    
+    dropped
+}
+
+/// Look up the context size of a loaded model, if the server reports one
+async fn fetch_context_size(client: &LmoClient, model_name: &str) -> Option<u32> {
+    let model_ref = ModelRef::parse(model_name).ok()?;
+    
+    client.loaded_models().await
+        .ok()?
+        .into_iter()
+        .find(|m| model_ref.matches_exact(&m.model_id))
+        .and_then(|m| m.context_size)
+}
+
+/// Ask the model for a one-paragraph summary of the session and print (and optionally save) it
+async fn summarize_on_exit(
+    client: &LmoClient,
+    cmd: &ChatCommand,
+    model_name: &str,
+    context_size: Option<u32>,
+    history: &[ChatMessage],
+    output: &OutputFormatter,
+) {
+    let exchanged = history.iter().filter(|msg| msg.role != "system").count();
+    if exchanged < MIN_MESSAGES_TO_SUMMARIZE {
+        output.debug("Conversation too short to summarize");
+        return;
+    }
+    
+    let mut messages = history.to_vec();
+    messages.push(ChatMessage {
+        role: "user".to_string(),
+        content: SUMMARY_INSTRUCTION.to_string(),
+        name: None,
+    });
+    
+    let mut request = build_request(cmd, model_name, messages, context_size);
+    request.stream = Some(false);
+    
+    output.status("Summarizing conversation...");
+    let summary = match timed(output, "chat_completion", client.chat_completion(request)).await {
+        Ok(response) => match response.choices.first() {
+            Some(choice) => choice.message.content.clone(),
+            None => {
+                output.warning("No summary generated");
+                return;
+            }
+        },
+        Err(e) => {
+            output.error(&format!("Failed to summarize conversation: {}", e));
+            return;
+        }
+    };
+    
+    println!();
+    output.subheader("Conversation Summary");
+    println!("{}", summary);
+    println!();
+    
+    // Save the summary next to the conversation history if one is being saved
+    if let Some(ref save_path) = cmd.save_history {
+        let summary_path = format!("{}.summary.txt", save_path);
+        match std::fs::write(&summary_path, &summary) {
+            Ok(_) => output.success(&format!("Summary saved to: {}", summary_path)),
+            Err(e) => output.error(&format!("Failed to save summary: {}", e)),
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,76 @@
+/*!
+ * Complete Command Implementation
+ * 
+ * Raw text completion for base models: the prompt is sent as-is, without the
+ * chat roles and template that `chat` applies.
+ */
+
+use anyhow::Result;
+use futures::StreamExt;
+use lmoserver::shared_types::CompletionRequest;
+use std::io::{self, Write};
+
+use crate::cli::CompleteCommand;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::output::OutputFormatter;
+use crate::utils::{create_client, create_streaming_client, check_server_health, read_input_text, timed};
+
+pub async fn handle(cmd: CompleteCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    
+    let model = cmd.model.clone()
+        .or_else(|| config.default_model.clone())
+        .ok_or_else(|| CliError::InvalidInput(
+            "No model given: pass --model or set default_model in the config".to_string()
+        ))?;
+    
+    // The prompt is sent verbatim, trailing whitespace included
+    let prompt = read_input_text(cmd.prompt.as_deref(), cmd.file.as_deref())?;
+    if prompt.is_empty() {
+        return Err(CliError::InvalidInput("Prompt is empty".to_string()).into());
+    }
+    
+    let client = create_client(config, None)?;
+    
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+    
+    let request = CompletionRequest {
+        model,
+        prompt,
+        max_tokens: Some(cmd.max_tokens.unwrap_or(config.chat.max_tokens)),
+        temperature: Some(cmd.temperature.unwrap_or(config.chat.temperature)),
+        stop: (!cmd.stop.is_empty()).then(|| cmd.stop.clone()),
+        stream: cmd.stream,
+    };
+    
+    if cmd.stream {
+        let stream_client = create_streaming_client(config)?;
+        let chunk_stream = timed(&output, "completion_stream", stream_client.completion_stream(request)).await?;
+        let mut stream = Box::pin(chunk_stream.into_stream());
+        
+        while let Some(chunk_result) = stream.next().await {
+            let chunk = chunk_result?;
+            if let Some(choice) = chunk.choices.first() {
+                print!("{}", choice.text);
+                io::stdout().flush()?;
+            }
+        }
+        println!();
+    } else {
+        output.progress("Generating");
+        let response = timed(&output, "completion", client.completion(request)).await?;
+        output.progress_done();
+        
+        if matches!(config.output_format.as_str(), "json" | "yaml") {
+            return output.print(&response);
+        }
+        
+        if let Some(choice) = response.choices.first() {
+            println!("{}", choice.text);
+        }
+    }
+    
+    Ok(())
+}
\ No newline at end of file
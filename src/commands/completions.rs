@@ -0,0 +1,21 @@
+/*!
+ * Completions Command Implementation
+ * 
+ * Generate shell completion scripts from the clap definitions.
+ */
+
+use anyhow::Result;
+use clap::CommandFactory;
+use std::io;
+
+use crate::cli::{Cli, CompletionsCommand};
+use crate::config::CliConfig;
+
+pub async fn handle(cmd: CompletionsCommand, _config: &CliConfig) -> Result<()> {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    
+    clap_complete::generate(cmd.shell, &mut command, name, &mut io::stdout());
+    
+    Ok(())
+}
\ No newline at end of file
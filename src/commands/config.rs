@@ -15,29 +15,52 @@ pub async fn handle(cmd: ConfigCommand, config: &CliConfig) -> Result<()> {
     match cmd.action {
         ConfigAction::Show => {
             output.header("Current Configuration");
-            println!();
-            output.print(config)?;
+            eprintln!();
+            output.print(&config.redacted())?;
         }
         ConfigAction::Set { key, value } => {
-            let mut new_config = config.clone();
+            // Start from the file as written so that flag and profile overrides aren't persisted
+            let mut new_config = CliConfig::load(config.source.as_deref())?;
             new_config.set_value(&key, &value)?;
             new_config.save()?;
             output.success(&format!("Set {} = {}", key, value));
         }
-        ConfigAction::Get { key } => {
-            let value = config.get_value(&key)?;
+        ConfigAction::Get { key, reveal } => {
+            // Secrets stay masked so they don't end up in terminal scrollback or logs
+            let value = if reveal { config.get_value(&key)? } else { config.redacted().get_value(&key)? };
             println!("{}", value);
         }
         ConfigAction::Init => {
-            let default_config = CliConfig::default();
+            let default_config = CliConfig { source: config.source.clone(), ..CliConfig::default() };
             default_config.save()?;
             output.success("Configuration initialized with defaults");
         }
         ConfigAction::Reset => {
-            let default_config = CliConfig::default();
+            let default_config = CliConfig { source: config.source.clone(), ..CliConfig::default() };
             default_config.save()?;
             output.success("Configuration reset to defaults");
         }
+        ConfigAction::ListProfiles => {
+            if config.profiles.is_empty() {
+                output.info("No profiles defined. Add a [profiles.<name>] section to the config file.");
+                return Ok(());
+            }
+            
+            output.header(&format!("Profiles ({})", config.profiles.len()));
+            eprintln!();
+            
+            for (name, profile) in &config.profiles {
+                let marker = if config.active_profile.as_deref() == Some(name) { "* " } else { "  " };
+                println!(
+                    "{}{:<16} {:<32} {:<8} {}",
+                    marker,
+                    name,
+                    profile.server_url.as_deref().unwrap_or("-"),
+                    profile.output_format.as_deref().unwrap_or("-"),
+                    if profile.auth_token.is_some() { "token set" } else { "" }
+                );
+            }
+        }
     }
     
     Ok(())
@@ -0,0 +1,205 @@
+/*!
+ * Dashboard Command Implementation
+ * 
+ * Live view of server health, loaded models and active downloads.
+ */
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use colored::*;
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue};
+use lmoclient::LmoClient;
+use lmoclient::models::DownloadStatus;
+use std::io::{self, IsTerminal, Write};
+use std::time::Duration;
+use tokio::signal;
+use tracing::debug;
+
+use crate::cli::DashboardCommand;
+use crate::commands::download::{progress_row, status_label};
+use crate::config::CliConfig;
+use crate::output::{OutputFormatter, fit_text};
+use crate::utils::{create_client, fetch_loaded_models, format_duration, retry_idempotent};
+
+/// Give up on a single refresh after this long and show it as failed
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn handle(cmd: DashboardCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    let client = create_client(config, None)?;
+    let interval = Duration::from_secs(cmd.interval.max(1));
+    
+    // Without a terminal to redraw, print a single snapshot
+    if !io::stdout().is_terminal() {
+        let frame = fetch_frame(&client, config.retries).await?;
+        for line in frame.lines(&output) {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+    
+    let _cursor = HiddenCursor::hide()?;
+    execute!(io::stdout(), terminal::Clear(ClearType::All))?;
+    
+    let ctrl_c = signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    
+    let mut last_frame: Option<Frame> = None;
+    let header = |refreshed_at: DateTime<Local>| format!(
+        "LMO Dashboard (every {}s, Ctrl+C to stop) • Last refresh: {}",
+        interval.as_secs(),
+        refreshed_at.format("%H:%M:%S")
+    );
+    
+    loop {
+        let fetched = tokio::select! {
+            result = tokio::time::timeout(FETCH_TIMEOUT, fetch_frame(&client, config.retries)) => result,
+            _ = &mut ctrl_c => break,
+        };
+        
+        let lines = match fetched {
+            Ok(Ok(frame)) => {
+                let mut lines = vec![header(frame.refreshed_at)];
+                lines.extend(frame.lines(&output));
+                last_frame = Some(frame);
+                lines
+            }
+            failed => {
+                let reason = match failed {
+                    Ok(Err(e)) => format!("{:#}", e),
+                    _ => format!("timed out after {}s", FETCH_TIMEOUT.as_secs()),
+                };
+                debug!("Dashboard refresh failed: {}", reason);
+                
+                // Keep the last good data on screen beneath the notice
+                let mut lines = match last_frame {
+                    Some(ref frame) => {
+                        let mut lines = vec![header(frame.refreshed_at)];
+                        lines.extend(frame.lines(&output));
+                        lines
+                    }
+                    None => vec![header(Local::now())],
+                };
+                lines.push(String::new());
+                lines.push(format!("Refresh failed: {}", reason));
+                lines
+            }
+        };
+        
+        draw(&lines)?;
+        
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = &mut ctrl_c => break,
+        }
+    }
+    
+    println!();
+    output.info("Stopped dashboard");
+    
+    Ok(())
+}
+
+/// Everything shown in one dashboard refresh
+struct Frame {
+    server: String,
+    loaded_models: Vec<String>,
+    downloads: Vec<String>,
+    refreshed_at: DateTime<Local>,
+}
+
+impl Frame {
+    fn lines(&self, output: &OutputFormatter) -> Vec<String> {
+        let title = |text: String| if output.no_color() { text } else { text.bold().to_string() };
+        let or_none = |rows: &[String]| if rows.is_empty() { vec!["  None".to_string()] } else { rows.to_vec() };
+        
+        let mut lines = vec![String::new(), title("Server".to_string()), self.server.clone(), String::new()];
+        
+        lines.push(title(format!("Loaded Models ({})", self.loaded_models.len())));
+        lines.extend(or_none(&self.loaded_models));
+        lines.push(String::new());
+        
+        lines.push(title(format!("Downloads ({})", self.downloads.len())));
+        lines.extend(or_none(&self.downloads));
+        lines
+    }
+}
+
+/// Fetch health, loaded instances and downloads concurrently
+async fn fetch_frame(client: &LmoClient, retries: u32) -> Result<Frame> {
+    let (health, loaded_models, downloads) = tokio::try_join!(
+        async { client.health().await.context("Failed to get server health") },
+        async { fetch_loaded_models(client, retries).await.context("Failed to get loaded models") },
+        async { retry_idempotent(retries, || client.download_list()).await.context("Failed to get downloads") },
+    )?;
+    
+    let server = format!(
+        "  {} • version {} • uptime {} • {}",
+        health.status,
+        health.server_version,
+        format_duration(health.uptime_seconds),
+        client.config().server_url
+    );
+    
+    let loaded_models = loaded_models.iter()
+        .map(|model| format!("  {} {:<38} {}", fit_text(&model.model_id, 40), model.instance_id, model.status))
+        .collect();
+    
+    // Finished, failed and cancelled downloads linger in the list but aren't worth watching
+    let downloads = downloads.iter()
+        .filter(|download| matches!(
+            download.status,
+            DownloadStatus::Pending | DownloadStatus::Downloading | DownloadStatus::Paused
+        ))
+        .map(|download| {
+            let progress = &download.progress;
+            format!("  {}", progress_row(
+                &download.model_name,
+                status_label(&download.status),
+                progress.percentage,
+                progress.downloaded_bytes,
+                progress.total_bytes,
+                progress.speed_bps,
+            ))
+        })
+        .collect();
+    
+    Ok(Frame { server, loaded_models, downloads, refreshed_at: Local::now() })
+}
+
+/// Redraw in place: overwrite each line and clear what's left, so the screen never blanks.
+/// Lines are clipped to the terminal width and cut off at its height so nothing wraps or scrolls.
+fn draw(lines: &[String]) -> Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let mut stdout = io::stdout();
+    
+    let shown = lines.len().min(rows as usize);
+    for (row, line) in lines.iter().take(shown).enumerate() {
+        queue!(stdout, cursor::MoveTo(0, row as u16))?;
+        write!(stdout, "{}", console::truncate_str(line, columns as usize, ""))?;
+        queue!(stdout, terminal::Clear(ClearType::UntilNewLine))?;
+    }
+    
+    if shown < rows as usize {
+        queue!(stdout, cursor::MoveTo(0, shown as u16), terminal::Clear(ClearType::FromCursorDown))?;
+    }
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Hides the cursor while the dashboard runs and restores it on exit, even on error
+struct HiddenCursor;
+
+impl HiddenCursor {
+    fn hide() -> Result<Self> {
+        execute!(io::stdout(), cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show);
+    }
+}
\ No newline at end of file
@@ -6,115 +6,868 @@
 
 use anyhow::Result;
 use futures::StreamExt;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use lmoclient::LmoClient;
+use lmoclient::models::DownloadStatus;
+use sha2::{Digest, Sha256};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use tokio::signal;
+use tracing::{debug, error, warn};
 
-use crate::cli::DownloadCommand;
+use crate::cli::{DownloadAction, DownloadCommand, ProgressMode};
 use crate::config::CliConfig;
-use crate::output::OutputFormatter;
-use crate::utils::{create_client, check_server_health};
+use crate::error::CliError;
+use crate::model_ref::{ModelRef, quantization_from_filename};
+use crate::output::{OutputFormatter, fit_text, format_bytes};
+use crate::utils::{create_streaming_client, check_server_health, format_duration, is_transient_error, notify_completion, retry_idempotent, timed, validate_server_url};
+
+/// Attempts made to start a download before giving up on transient errors
+const START_ATTEMPTS: u32 = 3;
 
 /// Handle download command with real-time progress
-pub async fn handle(cmd: DownloadCommand, config: &CliConfig) -> Result<()> {
+pub async fn handle(mut cmd: DownloadCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
-    let client = create_client(config, None)?;
+    // Downloads mostly follow long-lived progress streams, so no request timeout
+    let client = create_streaming_client(config)?;
     
     // Check server health first
-    check_server_health(&client, &output).await?;
+    check_server_health(&client, config.retries, &output).await?;
     
-    output.header(&format!("Downloading Model: {}", cmd.model_name));
-    println!();
+    if let Some(action) = cmd.action.take() {
+        return handle_action(action, &client, config, FollowSettings::from_command(&cmd, &output), &output).await;
+    }
     
-    // Validate model name format
-    if !cmd.model_name.contains('/') {
-        output.warning("Model name should include organization/repository (e.g., 'microsoft/DialoGPT-small')");
-        output.info("Attempting to download anyway...");
+    // clap guarantees at least one model name when no subcommand is given
+    let model_names = cmd.model_names.clone();
+    
+    // Resolve the mirror from the command line, falling back to the config file
+    let mirror = cmd.mirror.clone().or_else(|| config.download.mirror.clone());
+    if let Some(ref mirror) = mirror {
+        validate_server_url(mirror)?;
+    }
+    
+    // Scripts asking for --quiet or JSON progress get no banner or configuration
+    let chatty = FollowSettings::from_command(&cmd, &output).chatty();
+    if chatty {
+        if let [model_name] = model_names.as_slice() {
+            output.header(&format!("Downloading Model: {}", ModelRef::parse(model_name)?));
+        } else {
+            output.header(&format!("Downloading {} Models", model_names.len()));
+        }
+        eprintln!();
+        
+        // Show download configuration
+        output.subheader("Download Configuration");
+        output.detail("Model Name", &model_names.join(", "));
+        
+        if let Some(ref format) = cmd.format {
+            output.detail("Format Hint", format);
+        }
+        
+        if cmd.force {
+            output.detail("Force Re-download", "Yes");
+        }
+        
+        if let Some(ref directory) = cmd.directory {
+            output.detail("Custom Directory", directory);
+        }
+        
+        if let Some(parallel_files) = cmd.parallel_files {
+            output.detail("Parallel Files", &parallel_files.to_string());
+        }
+        
+        if let Some(max_speed) = cmd.max_speed {
+            output.detail("Max Speed", &format!("{}/s", format_bytes(max_speed)));
+        }
+        
+        if model_names.len() > 1 {
+            output.detail("Concurrent Downloads", &cmd.parallel.to_string());
+        }
+        
+        output.detail("Mirror", mirror.as_deref().unwrap_or("Default hub"));
+        
+        eprintln!();
+    }
+        
+    // The outcome was already reported; scripts still need a failing exit code
+    if let [model_name] = model_names.as_slice() {
+        let reason = match download_model(&client, config.retries, &cmd, model_name, mirror.as_deref(), None, &output).await? {
+            DownloadOutcome::Completed => return Ok(()),
+            DownloadOutcome::Failed(error) => format!("failed: {}", error.as_deref().unwrap_or("Unknown error")),
+            DownloadOutcome::Cancelled => "cancelled".to_string(),
+            DownloadOutcome::Interrupted => "progress lost".to_string(),
+        };
+        return Err(CliError::CommandError(format!("Download of {} did not complete ({})", model_name, reason)).into());
     }
     
-    // Show download configuration
-    output.subheader("Download Configuration");
-    output.key_value("Model Name", &cmd.model_name);
+    // Ctrl+C cancels active downloads (in follow_progress) and stops queued ones here
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let interrupted_flag = interrupted.clone();
+    let _interrupt_listener = AbortOnDrop(tokio::spawn(async move {
+        if signal::ctrl_c().await.is_ok() {
+            interrupted_flag.store(true, Ordering::SeqCst);
+        }
+    }));
+    
+    let multi_progress = (cmd.parallel > 1).then(MultiProgress::new);
     
-    if let Some(ref format) = cmd.format {
-        output.key_value("Format Hint", format);
+    let outcomes: Vec<(String, Result<DownloadOutcome>)> = futures::stream::iter(model_names.iter())
+        .map(|model_name| {
+            let interrupted = interrupted.clone();
+            let multi_progress = multi_progress.as_ref();
+            let mirror = mirror.as_deref();
+            let (client, cmd, output) = (&client, &cmd, &output);
+            
+            async move {
+                if interrupted.load(Ordering::SeqCst) {
+                    return (model_name.clone(), Ok(DownloadOutcome::Cancelled));
+                }
+                
+                let show_header = chatty && multi_progress.is_none();
+                if show_header {
+                    output.subheader(&format!("Model: {}", model_name));
+                }
+                let result = download_model(client, config.retries, cmd, model_name, mirror, multi_progress, output).await;
+                if show_header {
+                    eprintln!();
+                }
+                
+                (model_name.clone(), result)
+            }
+        })
+        .buffered(cmd.parallel as usize)
+        .collect()
+        .await;
+    
+    // One summary for the whole batch; a failure never stops the other downloads
+    eprintln!();
+    output.subheader("Download Summary");
+    
+    let mut failures = 0;
+    for (model_name, result) in &outcomes {
+        let summary = match result {
+            Ok(DownloadOutcome::Completed) => {
+                output.success(model_name);
+                continue;
+            }
+            Ok(DownloadOutcome::Failed(error)) => format!("failed: {}", error.as_deref().unwrap_or("Unknown error")),
+            Ok(DownloadOutcome::Cancelled) => "cancelled".to_string(),
+            Ok(DownloadOutcome::Interrupted) => "progress lost".to_string(),
+            Err(e) => format!("failed: {}", e),
+        };
+        failures += 1;
+        output.error(&format!("{} ({})", model_name, summary));
     }
     
-    if cmd.force {
-        output.key_value("Force Re-download", "Yes");
+    if failures > 0 {
+        return Err(CliError::CommandError(format!(
+            "{} of {} downloads did not complete",
+            failures,
+            outcomes.len()
+        )).into());
     }
     
-    if let Some(ref directory) = cmd.directory {
-        output.key_value("Custom Directory", directory);
+    output.success(&format!("All {} downloads completed", outcomes.len()));
+    Ok(())
+}
+
+/// Download a single model: optional quantization pick, resume or start, then
+/// follow its progress. With `multi_progress`, the bar joins the shared display.
+async fn download_model(
+    client: &LmoClient,
+    retries: u32,
+    cmd: &DownloadCommand,
+    model_name: &str,
+    mirror: Option<&str>,
+    multi_progress: Option<&MultiProgress>,
+    output: &OutputFormatter,
+) -> Result<DownloadOutcome> {
+    let model_ref = ModelRef::parse(model_name)?;
+    let chatty = FollowSettings::from_command(cmd, output).chatty();
+    
+    // Validate model name format
+    if model_ref.org.is_none() {
+        output.warning("Model name should include organization/repository (e.g., 'microsoft/DialoGPT-small')");
+        output.info("Attempting to download anyway...");
     }
     
-    println!();
+    // Let the user pick a quantization, or narrow to --file patterns, before anything is downloaded
+    let allow_patterns = if cmd.recommend {
+        match recommend_file(client, retries, &model_ref, cmd.max_memory_gb, output).await? {
+            Some(filename) => Some(vec![filename]),
+            None => return Ok(DownloadOutcome::Cancelled),
+        }
+    } else if !cmd.files.is_empty() {
+        let matched = match_repo_files(client, retries, &model_ref, &cmd.files).await?;
+        if chatty {
+            output.info(&format!("{} file(s) match: {}", matched.len(), matched.join(", ")));
+        }
+        Some(cmd.files.clone())
+    } else {
+        None
+    };
+    
+    // Pick up where an unfinished download of the same model left off
+    if cmd.resume {
+        if chatty {
+            output.progress("Looking for an unfinished download");
+        }
+        let downloads = timed(output, "download_list", client.download_list()).await?;
+        if chatty {
+            output.progress_done();
+        }
+        
+        let partial = downloads.iter().find(|d| {
+            d.model_name.eq_ignore_ascii_case(model_name) && !matches!(d.status, DownloadStatus::Completed)
+        });
+        
+        match partial {
+            Some(download) => {
+                let download_id = download.download_id.clone();
+                let percentage = download.progress.percentage;
+                
+                // Active downloads only need reattaching; stopped ones need resuming
+                if matches!(download.status, DownloadStatus::Paused | DownloadStatus::Failed | DownloadStatus::Cancelled) {
+                    timed(output, "download_resume", client.download_resume(&download_id)).await?;
+                }
+                
+                if chatty {
+                    output.success(&format!("Resuming download {} at {:.1}%", download_id, percentage));
+                    eprintln!();
+                }
+                
+                let outcome = finish_download(client, cmd, &model_ref, &download_id, percentage, multi_progress, output).await?;
+                return verify_and_notify(client, retries, cmd, &model_ref, outcome, output).await;
+            }
+            None if chatty => output.info("No unfinished download found; starting a new one"),
+            None => {}
+        }
+    }
     
     // Prepare download request
-    output.progress("Starting download...");
+    if chatty {
+        output.progress("Starting download...");
+    }
+    
+    // One key per logical download, reused on retries, so the server can
+    // collapse duplicate starts into a single download
+    let idempotency_key = uuid::Uuid::new_v4().to_string();
+    debug!("Download idempotency key: {}", idempotency_key);
     
     let download_request = lmoclient::models::DownloadModelRequest {
-        model_name: cmd.model_name.clone(),
+        model_name: model_name.to_string(),
         format_hint: cmd.format.clone(),
         force_redownload: cmd.force,
         custom_directory: cmd.directory.clone(),
+        parallel_files: cmd.parallel_files,
+        mirror_url: mirror.map(str::to_string),
+        idempotency_key: Some(idempotency_key),
+        allow_patterns,
+        max_speed_bps: cmd.max_speed,
     };
     
-    // Start the download and get download ID
-    let start_response = client.download_start(download_request).await?;
-    output.progress_done();
+    // Start the download and get download ID, retrying transient failures
+    let mut attempt = 1;
+    let start_response = loop {
+        match timed(output, "download_start", client.download_start(download_request.clone())).await {
+            Ok(response) => break response,
+            Err(e) if attempt < START_ATTEMPTS && is_transient_error(&e) => {
+                warn!("Download start failed ({}), retrying ({}/{})", e, attempt, START_ATTEMPTS - 1);
+                tokio::time::sleep(tokio::time::Duration::from_millis(500 * attempt as u64)).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                if chatty {
+                    output.progress_failed(&e.to_string());
+                }
+                return Err(e.into());
+            }
+        }
+    };
+    
+    if chatty {
+        output.progress_done();
+        output.success(&format!("✓ Download started: {}", start_response.download_id));
+        if let Some(size) = start_response.estimated_size_bytes {
+            output.detail("Estimated Size", &format_bytes(size));
+        }
+    }
+    
+    // Let the user know if the server adjusted the requested concurrency
+    if let Some(requested) = cmd.parallel_files {
+        match start_response.parallel_files {
+            Some(applied) if applied != requested => {
+                output.warning(&format!("Server clamped parallel files from {} to {}", requested, applied));
+            }
+            Some(_) => {}
+            None if chatty => output.info("Server did not confirm the parallel files setting; it may be ignored"),
+            None => {}
+        }
+    }
+    
+    // The server enforces the cap; the client cannot throttle a server-side transfer
+    if let Some(requested) = cmd.max_speed {
+        match start_response.max_speed_bps {
+            Some(applied) if applied != requested => {
+                output.warning(&format!(
+                    "Server adjusted the speed cap from {}/s to {}/s", format_bytes(requested), format_bytes(applied)
+                ));
+            }
+            Some(_) => {}
+            None => output.warning("Server did not confirm the speed cap; the download may run unthrottled"),
+        }
+    }
+    if chatty {
+        eprintln!();
+    }
+    
+    let outcome = finish_download(client, cmd, &model_ref, &start_response.download_id, 0.0, multi_progress, output).await?;
+    verify_and_notify(client, retries, cmd, &model_ref, outcome, output).await
+}
+
+/// Follow a started download to the end and report how it ended
+async fn finish_download(
+    client: &LmoClient,
+    cmd: &DownloadCommand,
+    model_ref: &ModelRef,
+    download_id: &str,
+    start_percentage: f64,
+    multi_progress: Option<&MultiProgress>,
+    output: &OutputFormatter,
+) -> Result<DownloadOutcome> {
+    let label = model_ref.to_string();
+    let settings = FollowSettings::from_command(cmd, output);
+    let outcome = follow_progress(client, download_id, &label, start_percentage, settings, multi_progress, output).await?;
+    
+    // Without the bar, the outcome line is the only trace of the download
+    if !settings.chatty() {
+        match &outcome {
+            // Quiet mode suppresses success chatter, so the result is reported as data
+            DownloadOutcome::Completed if settings.quiet => {
+                output.key_value("Downloaded", &format!("{} ({})", label, download_id));
+            }
+            DownloadOutcome::Completed => output.success(&format!("Downloaded {} ({})", label, download_id)),
+            DownloadOutcome::Failed(error) => output.error(&format!(
+                "Download of {} failed ({}): {}", label, download_id, error.as_deref().unwrap_or("Unknown error")
+            )),
+            DownloadOutcome::Cancelled => output.error(&format!("Download of {} cancelled ({})", label, download_id)),
+            DownloadOutcome::Interrupted => output.error(&format!("Lost progress of {} ({})", label, download_id)),
+        }
+    }
+    
+    Ok(outcome)
+}
+
+/// Verify the files of a completed download and send the notification
+async fn verify_and_notify(
+    client: &LmoClient,
+    retries: u32,
+    cmd: &DownloadCommand,
+    model_ref: &ModelRef,
+    outcome: DownloadOutcome,
+    output: &OutputFormatter,
+) -> Result<DownloadOutcome> {
+    let mismatches = match outcome {
+        DownloadOutcome::Completed if !cmd.no_verify => verify_download(client, retries, model_ref, output).await,
+        _ => Ok(0),
+    };
+    
+    // Only hashes that were actually compared can be a mismatch; anything else is reported as is
+    if cmd.notify {
+        let label = model_ref.to_string();
+        match mismatches {
+            Ok(0) => notify_outcome(&label, &outcome),
+            Ok(_) => notify_outcome(&label, &DownloadOutcome::Failed(Some("checksum mismatch".to_string()))),
+            Err(ref e) => notify_outcome(&label, &DownloadOutcome::Failed(Some(format!("verification failed: {}", e)))),
+        }
+    }
+    
+    match mismatches? {
+        0 => Ok(outcome),
+        mismatches => Err(CliError::CommandError(format!(
+            "{} file(s) failed checksum verification; re-download with --force",
+            mismatches
+        )).into()),
+    }
+}
+
+/// Compare each downloaded file against the SHA256 published in the registry's
+/// file metadata and return how many differ. Files without a published hash are skipped.
+async fn verify_download(client: &LmoClient, retries: u32, model_ref: &ModelRef, output: &OutputFormatter) -> Result<usize> {
+    let models_response = timed(output, "list_models", retry_idempotent(retries, || client.list_models())).await?;
+    let Some(model) = models_response.models.into_iter()
+        .find(|m| m.id.eq_ignore_ascii_case(&model_ref.repo_id())) else {
+        output.warning("Model not found in the registry; skipping checksum verification");
+        return Ok(0);
+    };
+    
+    let expected: Vec<(String, String)> = model.files.into_iter()
+        .filter_map(|file| Some((file.filename, file.sha256?)))
+        .collect();
+    if expected.is_empty() {
+        output.info("Server publishes no checksums for this model; skipping verification");
+        return Ok(0);
+    }
+    
+    let local_models = timed(output, "list_local_models", client.list_local_models()).await?.models;
+    
+    eprintln!();
+    output.subheader("Verifying Checksums");
+    
+    let mut mismatches = 0;
+    for (filename, sha256) in expected {
+        // Only files that were actually downloaded (e.g. one quantization) are checked;
+        // match whole file names so "model.gguf" can't pick up "other-model.gguf"
+        let file_name = Path::new(&filename).file_name();
+        let Some(local_model) = local_models.iter()
+            .find(|m| model_ref.matches_prefix(&m.filename) && Path::new(&m.filename).file_name() == file_name) else {
+            debug!("Skipping {}: not downloaded", filename);
+            continue;
+        };
+        
+        let path = PathBuf::from(&local_model.path);
+        let actual = tokio::task::spawn_blocking(move || sha256_file(&path)).await??;
+        
+        if actual.eq_ignore_ascii_case(&sha256) {
+            output.success(&filename);
+        } else {
+            mismatches += 1;
+            output.error(&format!("{} (expected {}, got {})", filename, sha256, actual));
+        }
+    }
+    
+    Ok(mismatches)
+}
+
+/// Stream a file through SHA256 and return the lowercase hex digest
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// How a download's progress is followed: stall limits and reporting mode
+#[derive(Debug, Clone, Copy)]
+struct FollowSettings {
+    timeout: Duration,
+    max_stalls: u32,
+    mode: ProgressMode,
+    quiet: bool,
+    max_speed: Option<u64>,
+}
+
+impl FollowSettings {
+    fn from_command(cmd: &DownloadCommand, output: &OutputFormatter) -> Self {
+        Self {
+            timeout: Duration::from_secs(cmd.stream_timeout),
+            max_stalls: cmd.max_stalls,
+            mode: cmd.progress,
+            quiet: output.is_quiet(),
+            max_speed: cmd.max_speed,
+        }
+    }
+    
+    /// Whether the progress bar and informational messages are shown
+    fn chatty(&self) -> bool {
+        !self.quiet && self.mode == ProgressMode::Bar
+    }
+}
+
+/// Aborts a background task when dropped
+struct AbortOnDrop(tokio::task::JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Handle the list/pause/resume download subcommands
+async fn handle_action(
+    action: DownloadAction,
+    client: &LmoClient,
+    config: &CliConfig,
+    settings: FollowSettings,
+    output: &OutputFormatter,
+) -> Result<()> {
+    match action {
+        DownloadAction::List { interactive: true } => {
+            manage_downloads(client, output).await?;
+        }
+        DownloadAction::List { interactive: false } => {
+            output.progress("Fetching active downloads");
+            let downloads = timed(output, "download_list", client.download_list()).await?;
+            output.progress_done();
+            
+            if matches!(config.output_format.as_str(), "json" | "yaml") {
+                let downloads: Vec<serde_json::Value> = downloads.iter().map(|download| {
+                    let progress = &download.progress;
+                    serde_json::json!({
+                        "download_id": download.download_id,
+                        "model_name": download.model_name,
+                        "status": download.status,
+                        "percentage": progress.percentage,
+                        "downloaded_bytes": progress.downloaded_bytes,
+                        "total_bytes": progress.total_bytes,
+                        "speed_bps": progress.speed_bps,
+                        "eta_seconds": progress.eta_seconds,
+                    })
+                }).collect();
+                output.print(&downloads)?;
+                return Ok(());
+            }
+            
+            if downloads.is_empty() {
+                output.info("No active downloads");
+                return Ok(());
+            }
+            
+            println!("{:<38} {:<40} {:>7} {:>12} {:>8} {:<12}", "Download ID", "Model", "Percent", "Speed", "ETA", "Status");
+            println!("{}", "-".repeat(122));
+            
+            for download in &downloads {
+                let progress = &download.progress;
+                let speed = if progress.speed_bps > 0.0 {
+                    format!("{}/s", format_bytes(progress.speed_bps as u64))
+                } else {
+                    "-".to_string()
+                };
+                let eta = progress.eta_seconds
+                    .filter(|eta| *eta > 0.0)
+                    .map_or("-".to_string(), |eta| format_duration(eta as u64));
+                
+                println!("{:<38} {} {:>6.1}% {:>12} {:>8} {:<12}",
+                    download.download_id,
+                    fit_text(&download.model_name, 40),
+                    progress.percentage,
+                    speed,
+                    eta,
+                    status_label(&download.status)
+                );
+            }
+        }
+        DownloadAction::Pause { download_id } => {
+            output.progress(&format!("Pausing download {}", download_id));
+            timed(output, "download_pause", client.download_pause(&download_id)).await?;
+            output.progress_done();
+            
+            output.success(&format!("Download paused: {}", download_id));
+            output.info(&format!("Resume it with 'lmo download resume {}'", download_id));
+        }
+        DownloadAction::Resume { download_id } => {
+            output.progress(&format!("Resuming download {}", download_id));
+            timed(output, "download_resume", client.download_resume(&download_id)).await?;
+            output.progress_done();
+            
+            output.success(&format!("Download resumed: {}", download_id));
+            eprintln!();
+            
+            // Start the bar where the download already is rather than at zero
+            let percentage = client.download_list().await
+                .ok()
+                .and_then(|downloads| downloads.into_iter().find(|d| d.download_id == download_id))
+                .map_or(0.0, |d| d.progress.percentage);
+            
+            follow_progress(client, &download_id, &download_id, percentage, settings, None, output).await?;
+        }
+    }
     
-    output.success(&format!("✓ Download started: {}", start_response.download_id));
-    if let Some(size) = start_response.estimated_size_bytes {
-        output.key_value("Estimated Size", &format_bytes(size));
+    Ok(())
+}
+
+/// How often the interactive download manager refreshes the list
+const MANAGER_REFRESH: Duration = Duration::from_secs(1);
+
+/// Width of the per-download progress bar in the interactive manager
+const MANAGER_BAR_WIDTH: usize = 20;
+
+/// One download as a text progress bar row, shared by the manager and the dashboard
+pub fn progress_row(model_name: &str, status: &str, percentage: f64, downloaded_bytes: u64, total_bytes: u64, speed_bps: f64) -> String {
+    let filled = ((percentage / 100.0) * MANAGER_BAR_WIDTH as f64).round() as usize;
+    let filled = filled.min(MANAGER_BAR_WIDTH);
+    
+    let mut row = format!("{} [{}{}] {:>5.1}% {:<12}",
+        fit_text(model_name, 40),
+        "#".repeat(filled),
+        "-".repeat(MANAGER_BAR_WIDTH - filled),
+        percentage,
+        status
+    );
+    if total_bytes > 0 {
+        row.push_str(&format!(" {}/{}", format_bytes(downloaded_bytes), format_bytes(total_bytes)));
+    }
+    if speed_bps > 0.0 {
+        row.push_str(&format!(" {}/s", format_bytes(speed_bps as u64)));
     }
+    row
+}
+
+/// Restores the terminal when the interactive manager exits, even on error
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enable() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Live, refreshing view of all active downloads with pause/resume/cancel keys.
+/// Leaving the view never touches the downloads themselves.
+async fn manage_downloads(client: &LmoClient, output: &OutputFormatter) -> Result<()> {
+    if !io::stdout().is_terminal() {
+        return Err(CliError::InvalidInput("--interactive requires a terminal".to_string()).into());
+    }
+    
+    let mut downloads = client.download_list().await?;
+    let mut selected = 0;
+    let mut message = String::new();
+    let mut last_refresh = Instant::now();
+    let mut dirty = true;
+    
+    let guard = RawModeGuard::enable()?;
+    
+    loop {
+        selected = selected.min(downloads.len().saturating_sub(1));
+        
+        if dirty {
+            let rows: Vec<String> = downloads.iter().map(|download| {
+                let progress = &download.progress;
+                progress_row(
+                    &download.model_name,
+                    status_label(&download.status),
+                    progress.percentage,
+                    progress.downloaded_bytes,
+                    progress.total_bytes,
+                    progress.speed_bps,
+                )
+            }).collect();
+            
+            render_manager(&rows, selected, &message)?;
+            dirty = false;
+        }
+        
+        // Poll briefly so list refreshes keep flowing while waiting for keys
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                
+                let current = downloads.get(selected).map(|d| d.download_id.clone());
+                let is_ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                
+                match (key.code, current) {
+                    (KeyCode::Char('q') | KeyCode::Esc, _) => break,
+                    _ if is_ctrl_c => break,
+                    (KeyCode::Up | KeyCode::Char('k'), _) => selected = selected.saturating_sub(1),
+                    (KeyCode::Down | KeyCode::Char('j'), _) => selected += 1,
+                    (KeyCode::Char('p'), Some(id)) => {
+                        message = action_message("Paused", &id, client.download_pause(&id).await);
+                        last_refresh -= MANAGER_REFRESH;
+                    }
+                    (KeyCode::Char('r'), Some(id)) => {
+                        message = action_message("Resumed", &id, client.download_resume(&id).await);
+                        last_refresh -= MANAGER_REFRESH;
+                    }
+                    (KeyCode::Char('c'), Some(id)) => {
+                        message = action_message("Cancelled", &id, client.download_cancel(&id).await);
+                        last_refresh -= MANAGER_REFRESH;
+                    }
+                    _ => {}
+                }
+                dirty = true;
+            }
+        }
+        
+        if last_refresh.elapsed() >= MANAGER_REFRESH {
+            match client.download_list().await {
+                Ok(latest) => downloads = latest,
+                Err(e) => message = format!("Failed to refresh downloads: {}", e),
+            }
+            last_refresh = Instant::now();
+            dirty = true;
+        }
+    }
+    
+    drop(guard);
     println!();
+    output.info("Left the download manager - downloads keep running on the server");
+    
+    Ok(())
+}
+
+/// Draw the interactive manager screen (raw mode needs explicit `\r\n`)
+fn render_manager(rows: &[String], selected: usize, message: &str) -> Result<()> {
+    let mut stdout = io::stdout();
+    queue!(stdout, cursor::MoveTo(0, 0), terminal::Clear(ClearType::All))?;
     
-    // Create progress bar
-    let progress_bar = ProgressBar::new(100);
+    write!(stdout, "Active Downloads ({})\r\n\r\n", rows.len())?;
+    
+    if rows.is_empty() {
+        write!(stdout, "  No active downloads\r\n")?;
+    }
+    for (i, row) in rows.iter().enumerate() {
+        let marker = if i == selected { ">" } else { " " };
+        write!(stdout, "{} {}\r\n", marker, row)?;
+    }
+    
+    write!(stdout, "\r\n{}\r\n", message)?;
+    write!(stdout, "↑/↓ select • p pause • r resume • c cancel • q quit\r\n")?;
+    stdout.flush()?;
+    
+    Ok(())
+}
+
+/// Status line describing the outcome of a pause/resume/cancel key press
+fn action_message<T>(action: &str, download_id: &str, result: Result<T, lmoclient::ClientError>) -> String {
+    match result {
+        Ok(_) => format!("{} {}", action, download_id),
+        Err(e) => format!("Failed to update {}: {}", download_id, e),
+    }
+}
+
+/// Human-readable download status for tables; JSON output uses the serde form
+pub fn status_label(status: &DownloadStatus) -> &'static str {
+    match status {
+        DownloadStatus::Pending => "Pending",
+        DownloadStatus::Downloading => "Downloading",
+        DownloadStatus::Paused => "Paused",
+        DownloadStatus::Completed => "Completed",
+        DownloadStatus::Failed => "Failed",
+        DownloadStatus::Cancelled => "Cancelled",
+    }
+}
+
+/// Send a completion notification describing how a download ended
+fn notify_outcome(model_name: &str, outcome: &DownloadOutcome) {
+    match outcome {
+        DownloadOutcome::Completed => notify_completion("Download completed", model_name, true),
+        DownloadOutcome::Failed(error) => notify_completion(
+            "Download failed",
+            &format!("{}: {}", model_name, error.as_deref().unwrap_or("Unknown error")),
+            false,
+        ),
+        DownloadOutcome::Cancelled => notify_completion("Download cancelled", model_name, false),
+        DownloadOutcome::Interrupted => notify_completion("Download progress lost", model_name, false),
+    }
+}
+
+/// How a followed download ended
+enum DownloadOutcome {
+    Completed,
+    Failed(Option<String>),
+    Cancelled,
+    /// The progress stream ended or stalled before a final event arrived
+    Interrupted,
+}
+
+/// Follow a download's progress stream with a progress bar until it finishes.
+/// Ctrl+C cancels the download on the server. With `multi_progress`, the bar
+/// is labelled with `label` and drawn alongside other downloads.
+async fn follow_progress(
+    client: &LmoClient,
+    download_id: &str,
+    label: &str,
+    start_percentage: f64,
+    settings: FollowSettings,
+    multi_progress: Option<&MultiProgress>,
+    output: &OutputFormatter,
+) -> Result<DownloadOutcome> {
+    // Create progress bar, hidden unless progress is shown as a bar
+    let progress_bar = match multi_progress {
+        _ if !settings.chatty() => ProgressBar::hidden(),
+        Some(multi_progress) => {
+            let progress_bar = multi_progress.add(ProgressBar::new(100));
+            progress_bar.set_prefix(fit_text(label, 30));
+            progress_bar
+        }
+        None => ProgressBar::new(100),
+    };
+    progress_bar.set_position(start_percentage.round() as u64);
+    let template = if multi_progress.is_some() {
+        "{prefix} [{wide_bar:.cyan/blue}] {percent:>3}% {msg}"
+    } else {
+        "{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {percent:>3}% {msg}"
+    };
     progress_bar.set_style(
         ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {percent:>3}% {msg}")
+            .template(template)
             .expect("Invalid progress bar template")
             .progress_chars("#>-")
     );
     
     // Start SSE stream for progress updates
-    let progress_stream = client.download_progress_stream(&start_response.download_id).await?;
+    let progress_stream = timed(output, "download_progress_stream", client.download_progress_stream(download_id)).await?;
     let mut stream = Box::pin(progress_stream.into_stream());
     
-    // Handle Ctrl+C for download cancellation
-    let download_id = start_response.download_id.clone();
+    // Handle Ctrl+C for download cancellation while this download is followed
+    let cancel_id = download_id.to_string();
     let client_clone = client.clone();
-    tokio::spawn(async move {
+    let _cancel_listener = AbortOnDrop(tokio::spawn(async move {
         match signal::ctrl_c().await {
             Ok(()) => {
                 eprintln!("\nReceived Ctrl+C, cancelling download...");
-                if let Err(e) = client_clone.download_cancel(&download_id).await {
-                    eprintln!("Error cancelling download: {}", e);
+                if let Err(e) = client_clone.download_cancel(&cancel_id).await {
+                    error!("Error cancelling download: {}", e);
                 }
             }
             Err(e) => {
-                eprintln!("Error setting up Ctrl+C handler: {}", e);
+                warn!("Error setting up Ctrl+C handler: {}", e);
             }
         }
-    });
+    }));
+    
+    // Emoji for terminals, plain tags for logs and --no-color
+    let icon = |emoji: &'static str, plain: &'static str| if output.no_color() { plain } else { emoji };
     
     // Stream progress updates with timeout
-    let mut last_status = String::new();
+    let mut last_status = None;
     let mut no_events_count = 0;
+    let mut last_percentage = start_percentage;
     
     loop {
         // Add timeout to prevent hanging
-        let timeout_duration = tokio::time::Duration::from_secs(30);
-        
-        match tokio::time::timeout(timeout_duration, stream.next()).await {
+        match tokio::time::timeout(settings.timeout, stream.next()).await {
             Ok(Some(event_result)) => {
                 no_events_count = 0; // Reset counter
                 
                 match event_result {
                     Ok(event) => {
                         let progress = &event.state.progress;
+                        last_percentage = last_percentage.max(progress.percentage);
+                        
+                        if settings.mode == ProgressMode::Json {
+                            let line = serde_json::json!({
+                                "download_id": download_id,
+                                "model": label,
+                                "event": event.event_type,
+                                "status": event.state.status,
+                                "percentage": progress.percentage,
+                                "downloaded_bytes": progress.downloaded_bytes,
+                                "total_bytes": progress.total_bytes,
+                                "speed_bps": progress.speed_bps,
+                                "eta_seconds": progress.eta_seconds,
+                            });
+                            println!("{}", line);
+                        }
                         
                         // Update progress bar (round to nearest integer)
                         progress_bar.set_position(progress.percentage.round() as u64);
@@ -130,8 +883,13 @@ pub async fn handle(cmd: DownloadCommand, config: &CliConfig) -> Result<()> {
                             ));
                         }
                         
-                        if progress.speed_bps > 0.0 {
-                            msg_parts.push(format!("{}/s", format_bytes(progress.speed_bps as u64)));
+                        match (progress.speed_bps > 0.0, settings.max_speed) {
+                            (true, Some(cap)) => msg_parts.push(format!(
+                                "{}/s (cap {}/s)", format_bytes(progress.speed_bps as u64), format_bytes(cap)
+                            )),
+                            (true, None) => msg_parts.push(format!("{}/s", format_bytes(progress.speed_bps as u64))),
+                            (false, Some(cap)) => msg_parts.push(format!("cap {}/s", format_bytes(cap))),
+                            (false, None) => {}
                         }
                         
                         if let Some(eta) = progress.eta_seconds {
@@ -150,46 +908,49 @@ pub async fn handle(cmd: DownloadCommand, config: &CliConfig) -> Result<()> {
                         progress_bar.set_message(status_msg.clone());
                         
                         // Update status only if changed
-                        if last_status != format!("{:?}", event.state.status) {
-                            last_status = format!("{:?}", event.state.status);
+                        let status = status_label(&event.state.status);
+                        if last_status != Some(status) {
+                            last_status = Some(status);
                             match event.event_type {
                                 lmoclient::DownloadEventType::Started => {
-                                    progress_bar.println("📥 Download started");
+                                    progress_bar.println(format!("{} Download started", icon("📥", "[started]")));
                                 }
                                 lmoclient::DownloadEventType::FileCompleted => {
                                     if let Some(ref file) = progress.current_file {
-                                        progress_bar.println(&format!("✓ Completed: {}", file));
+                                        progress_bar.println(&format!("{} Completed: {}", icon("✓", "[ok]"), file));
                                     }
                                 }
                                 lmoclient::DownloadEventType::Paused => {
-                                    progress_bar.println("⏸️  Download paused");
+                                    progress_bar.println(format!("{} Download paused", icon("⏸️ ", "[paused]")));
                                 }
                                 lmoclient::DownloadEventType::Resumed => {
-                                    progress_bar.println("▶️  Download resumed");
+                                    progress_bar.println(format!("{} Download resumed", icon("▶️ ", "[resumed]")));
                                 }
                                 lmoclient::DownloadEventType::Completed => {
-                                    progress_bar.finish_with_message("✅ Download completed!");
-                                    println!();
-                                    output.success("Model is now available for loading with 'lmo load'");
-                                    break;
+                                    progress_bar.finish_with_message(format!("{} Download completed!", icon("✅", "[ok]")));
+                                    if settings.chatty() {
+                                        eprintln!();
+                                        output.success("Model is now available for loading with 'lmo load'");
+                                    }
+                                    return Ok(DownloadOutcome::Completed);
                                 }
                                 lmoclient::DownloadEventType::Failed => {
-                                    progress_bar.abandon_with_message("❌ Download failed!");
+                                    progress_bar.abandon_with_message(format!("{} Download failed!", icon("❌", "[error]")));
                                     if let Some(ref error) = event.state.error_message {
                                         output.warning(&format!("Error: {}", error));
                                     }
-                                    return Ok(());
+                                    return Ok(DownloadOutcome::Failed(event.state.error_message.clone()));
                                 }
                                 lmoclient::DownloadEventType::Cancelled => {
-                                    progress_bar.abandon_with_message("🛑 Download cancelled");
-                                    return Ok(());
+                                    progress_bar.abandon_with_message(format!("{} Download cancelled", icon("🛑", "[cancelled]")));
+                                    return Ok(DownloadOutcome::Cancelled);
                                 }
                                 _ => {} // Progress updates don't need special handling
                             }
                         }
                     }
                     Err(e) => {
-                        progress_bar.abandon_with_message("❌ Stream error!");
+                        progress_bar.abandon_with_message(format!("{} Stream error!", icon("❌", "[error]")));
                         output.warning(&format!("Stream error: {}", e));
                         
                         // Check if this is a common error and provide helpful guidance
@@ -207,15 +968,27 @@ pub async fn handle(cmd: DownloadCommand, config: &CliConfig) -> Result<()> {
             }
             Ok(None) => {
                 // Stream ended
-                progress_bar.abandon_with_message("📡 Stream ended");
+                progress_bar.abandon_with_message(format!("{} Stream ended", icon("📡", "[ended]")));
                 output.info("Download stream ended");
                 break;
             }
             Err(_timeout) => {
-                // Timeout occurred
+                // A quiet stream is fine as long as the download itself is advancing
+                let percentage = client.download_list().await
+                    .ok()
+                    .and_then(|downloads| downloads.into_iter().find(|d| d.download_id == download_id))
+                    .map(|d| d.progress.percentage);
+                if let Some(percentage) = percentage.filter(|p| *p > last_percentage) {
+                    debug!("No stream events, but progress advanced to {:.1}%", percentage);
+                    last_percentage = percentage;
+                    progress_bar.set_position(percentage.round() as u64);
+                    no_events_count = 0;
+                    continue;
+                }
+                
                 no_events_count += 1;
-                if no_events_count >= 3 {
-                    progress_bar.abandon_with_message("⏰ Stream timeout");
+                if no_events_count >= settings.max_stalls {
+                    progress_bar.abandon_with_message(format!("{} Stream timeout", icon("⏰", "[timeout]")));
                     output.warning("Download stream timed out - no progress updates received");
                     break;
                 } else {
@@ -225,23 +998,116 @@ pub async fn handle(cmd: DownloadCommand, config: &CliConfig) -> Result<()> {
         }
     }
     
-    Ok(())
+    Ok(DownloadOutcome::Interrupted)
 }
 
-/// Format bytes into human readable format
-fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
+/// Match `--file` globs against the repository's file list so that a typo fails
+/// before anything is downloaded. Returns the matching filenames.
+async fn match_repo_files(client: &LmoClient, retries: u32, model_ref: &ModelRef, patterns: &[String]) -> Result<Vec<String>> {
+    let patterns = patterns.iter()
+        .map(|pattern| glob::Pattern::new(pattern)
+            .map_err(|e| CliError::InvalidInput(format!("Invalid --file pattern '{}': {}", pattern, e))))
+        .collect::<Result<Vec<_>, _>>()?;
     
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
+    let models_response = retry_idempotent(retries, || client.list_models()).await?;
+    let model = models_response.models.into_iter()
+        .find(|m| m.id.eq_ignore_ascii_case(&model_ref.repo_id()))
+        .ok_or_else(|| CliError::ModelNotFound(model_ref.repo_id()))?;
+    
+    let matched: Vec<String> = model.files.into_iter()
+        .map(|file| file.filename)
+        .filter(|filename| patterns.iter().any(|pattern| pattern.matches(filename)))
+        .collect();
+    
+    if matched.is_empty() {
+        return Err(CliError::InvalidInput(format!(
+            "No files in {} match {}",
+            model_ref.repo_id(),
+            patterns.iter().map(|p| format!("'{}'", p)).collect::<Vec<_>>().join(", ")
+        )).into());
     }
     
-    if unit_index == 0 {
-        format!("{} {}", bytes, UNITS[unit_index])
-    } else {
-        format!("{:.1} {}", size, UNITS[unit_index])
+    Ok(matched)
+}
+
+/// Extra memory needed beyond the file size to run a model (KV cache, buffers)
+const MEMORY_OVERHEAD_FACTOR: f64 = 1.2;
+
+/// Show the repository's quantized files, recommend the largest one that fits in
+/// memory, and let the user confirm or pick another. Returns the chosen filename.
+async fn recommend_file(
+    client: &LmoClient,
+    retries: u32,
+    model_ref: &ModelRef,
+    max_memory_gb: Option<f64>,
+    output: &OutputFormatter,
+) -> Result<Option<String>> {
+    output.progress("Fetching repository files");
+    let models_response = timed(output, "list_models", retry_idempotent(retries, || client.list_models())).await?;
+    output.progress_done();
+    
+    let model = models_response.models.into_iter()
+        .find(|m| m.id.eq_ignore_ascii_case(&model_ref.repo_id()))
+        .ok_or_else(|| CliError::ModelNotFound(model_ref.repo_id()))?;
+    
+    // Only quantized files are interesting here; sort smallest first
+    let mut candidates: Vec<(String, String, u64)> = model.files.iter()
+        .filter_map(|file| {
+            let quant = quantization_from_filename(&file.filename)?;
+            Some((file.filename.clone(), quant, file.size_bytes.unwrap_or(0)))
+        })
+        .collect();
+    candidates.sort_by_key(|(_, _, size)| *size);
+    
+    if candidates.is_empty() {
+        output.warning("No quantized files found in this repository");
+        return Ok(None);
+    }
+    
+    let memory_limit = max_memory_gb.map(|gb| (gb * 1024.0 * 1024.0 * 1024.0) as u64);
+    let fits = |size: u64| match memory_limit {
+        Some(limit) => (size as f64 * MEMORY_OVERHEAD_FACTOR) as u64 <= limit,
+        None => true,
+    };
+    
+    // Largest file that still fits
+    let recommended = memory_limit.and_then(|_| candidates.iter().rposition(|(_, _, size)| fits(*size)));
+    
+    eprintln!();
+    output.subheader("Available Quantizations");
+    let choices: Vec<String> = candidates.iter()
+        .enumerate()
+        .map(|(i, (filename, quant, size))| {
+            let marker = if Some(i) == recommended {
+                "★ recommended"
+            } else if !fits(*size) {
+                "too large"
+            } else {
+                ""
+            };
+            format!("{:<10} {:>10}  {}  {}", quant, format_bytes(*size), filename, marker)
+        })
+        .collect();
+    
+    match (memory_limit, recommended) {
+        (None, _) => output.info("Pass --max-memory-gb to get a recommendation"),
+        (Some(_), None) => output.warning("None of the quantizations fit in the given memory"),
+        (Some(_), Some(_)) => {}
     }
+    
+    // Without a terminal, go with the recommendation (or print the list and stop)
+    if !std::io::stdin().is_terminal() {
+        for choice in &choices {
+            println!("{}", choice);
+        }
+        return Ok(recommended.map(|i| candidates[i].0.clone()));
+    }
+    
+    let selection = dialoguer::Select::new()
+        .with_prompt("Select a file to download")
+        .items(&choices)
+        .default(recommended.unwrap_or(0))
+        .interact_opt()?;
+    
+    Ok(selection.map(|i| candidates[i].0.clone()))
 }
\ No newline at end of file
@@ -0,0 +1,78 @@
+/*!
+ * Embeddings Command Implementation
+ * 
+ * Generate text embeddings with a loaded model.
+ */
+
+use anyhow::Result;
+use lmoserver::shared_types::EmbeddingRequest;
+
+use crate::cli::EmbeddingsCommand;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::output::{OutputFormatter, truncate_text};
+use crate::utils::{create_client, check_server_health, read_input_text, timed};
+
+/// Vector components shown in the table preview
+const PREVIEW_VALUES: usize = 6;
+
+pub async fn handle(cmd: EmbeddingsCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    
+    let model = cmd.model.clone()
+        .or_else(|| config.default_model.clone())
+        .ok_or_else(|| CliError::InvalidInput(
+            "No model given: pass --model or set default_model in the config".to_string()
+        ))?;
+    
+    // A file is a batch with one input per line; anything else is a single input
+    let text = read_input_text(cmd.text.as_deref(), cmd.file.as_deref())?;
+    let inputs: Vec<String> = if cmd.file.is_some() {
+        text.lines().map(str::trim).filter(|line| !line.is_empty()).map(str::to_string).collect()
+    } else {
+        vec![text.trim_end().to_string()]
+    };
+    
+    if inputs.iter().all(|input| input.is_empty()) {
+        return Err(CliError::InvalidInput("Nothing to embed".to_string()).into());
+    }
+    
+    let client = create_client(config, None)?;
+    
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+    
+    output.progress(&format!("Generating {} embedding(s)", inputs.len()));
+    let response = timed(&output, "embeddings", client.embeddings(EmbeddingRequest {
+        model: model.clone(),
+        input: inputs.clone(),
+    })).await?;
+    output.progress_done();
+    
+    if matches!(config.output_format.as_str(), "json" | "yaml") {
+        return output.print(&response);
+    }
+    
+    eprintln!();
+    output.subheader(&format!("Embeddings ({}, {})", response.data.len(), model));
+    
+    for embedding in &response.data {
+        let preview: Vec<String> = embedding.embedding.iter()
+            .take(PREVIEW_VALUES)
+            .map(|value| format!("{:.4}", value))
+            .collect();
+        let ellipsis = if embedding.embedding.len() > PREVIEW_VALUES { ", ..." } else { "" };
+        let input = inputs.get(embedding.index).map(String::as_str).unwrap_or("");
+        
+        println!(
+            "{:>4}  {:>5} dims  [{}{}]  {}",
+            embedding.index,
+            embedding.embedding.len(),
+            preview.join(", "),
+            ellipsis,
+            truncate_text(input, 40)
+        );
+    }
+    
+    Ok(())
+}
\ No newline at end of file
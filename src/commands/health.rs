@@ -5,25 +5,39 @@
  */
 
 use anyhow::Result;
+use lmoclient::LmoClient;
+use lmoclient::models::HealthResponse;
+use std::time::{Duration, Instant};
 use crate::cli::HealthCommand;
 use crate::config::CliConfig;
+use crate::error::CliError;
 use crate::output::{OutputFormatter, format_bytes};
-use crate::utils::{create_client, format_duration};
+use crate::utils::{create_client, format_duration, is_transient_error, retry_idempotent, spinner, timed};
 
 pub async fn handle(cmd: HealthCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
     let client = create_client(config, None)?;
     
-    output.progress("Checking server health");
-    
-    let health = client.health().await?;
+    let health = if cmd.wait {
+        let health = wait_until_healthy(&client, Duration::from_secs(cmd.wait_timeout), &output).await?;
+        output.success(&format!("Server is healthy (uptime: {})", format_duration(health.uptime_seconds)));
+        health
+    } else {
+        output.progress("Checking server health");
+        let health = timed(&output, "health", retry_idempotent(config.retries, || client.health())).await?;
+        output.progress_done();
+        health
+    };
     
-    output.progress_done();
+    if matches!(config.output_format.as_str(), "json" | "yaml") {
+        output.print(&health)?;
+        return check_status(&health.status, cmd.strict);
+    }
     
     if cmd.detailed {
         // Detailed health information
         output.header("Server Health Status");
-        println!();
+        eprintln!();
         
         output.key_value("Status", &health.status);
         
@@ -45,5 +59,51 @@ pub async fn handle(cmd: HealthCommand, config: &CliConfig) -> Result<()> {
         output.info(&format!("Server version: {}", health.server_version));
     }
     
-    Ok(())
+    check_status(&health.status, cmd.strict)
+}
+
+/// Fail with exit code 4 unless the server is healthy. A degraded server
+/// passes unless `strict` is set.
+fn check_status(status: &str, strict: bool) -> Result<()> {
+    match status {
+        "healthy" => Ok(()),
+        "degraded" if !strict => Ok(()),
+        _ => Err(CliError::ServerUnhealthy(status.to_string()).into()),
+    }
+}
+
+/// How often the health endpoint is polled with --wait
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Poll the health endpoint until the server reports healthy, showing a spinner
+/// with the attempt count. An unreachable server counts as not ready yet.
+async fn wait_until_healthy(client: &LmoClient, timeout: Duration, output: &OutputFormatter) -> Result<HealthResponse> {
+    let spinner = spinner(output, "{spinner:.green} Waiting for server [{elapsed_precise}] {msg}");
+    
+    let started = Instant::now();
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match client.health().await {
+            Ok(health) if health.status == "healthy" => {
+                spinner.finish_and_clear();
+                return Ok(health);
+            }
+            Ok(health) => spinner.set_message(format!("attempt {}: {}", attempt, health.status)),
+            Err(e) if is_transient_error(&e) => spinner.set_message(format!("attempt {}: not reachable", attempt)),
+            Err(e) => {
+                spinner.abandon();
+                return Err(e.into());
+            }
+        }
+        
+        if started.elapsed() >= timeout {
+            spinner.abandon_with_message("timed out");
+            return Err(CliError::ServerUnhealthy(format!(
+                "not healthy after {}s ({} attempts)", timeout.as_secs(), attempt
+            )).into());
+        }
+        
+        tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+    }
 }
\ No newline at end of file
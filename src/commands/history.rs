@@ -0,0 +1,92 @@
+/*!
+ * History Command Implementation
+ * 
+ * Inspect and compare saved chat conversations.
+ */
+
+use anyhow::Result;
+use lmoserver::shared_types::ChatMessage;
+
+use crate::cli::{HistoryAction, HistoryCommand};
+use crate::commands::chat::load_conversation_history;
+use crate::config::CliConfig;
+use crate::output::OutputFormatter;
+
+pub async fn handle(cmd: HistoryCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    
+    match cmd.action {
+        HistoryAction::Diff { a, b, only_assistant } => {
+            diff(&a, &b, only_assistant, config.chat.max_history_bytes, &output)
+        }
+    }
+}
+
+/// Compare two conversations turn by turn
+fn diff(path_a: &str, path_b: &str, only_assistant: bool, max_bytes: u64, output: &OutputFormatter) -> Result<()> {
+    let mut history_a = load_conversation_history(path_a, max_bytes)?;
+    let mut history_b = load_conversation_history(path_b, max_bytes)?;
+    
+    if only_assistant {
+        history_a.retain(|msg| msg.role == "assistant");
+        history_b.retain(|msg| msg.role == "assistant");
+    }
+    
+    output.header(&format!("Comparing {} ↔ {}", path_a, path_b));
+    output.info(&format!("{} vs {} messages", history_a.len(), history_b.len()));
+    eprintln!();
+    
+    let mut first_divergence = None;
+    let mut differences = 0;
+    
+    for i in 0..history_a.len().max(history_b.len()) {
+        let (msg_a, msg_b) = (history_a.get(i), history_b.get(i));
+        
+        if let (Some(a), Some(b)) = (msg_a, msg_b) {
+            if a.role == b.role && a.content == b.content {
+                output.diff_line(' ', &format!("#{} {}: identical", i + 1, a.role));
+                continue;
+            }
+        }
+        
+        differences += 1;
+        first_divergence.get_or_insert(i + 1);
+        
+        let role = msg_a.or(msg_b).map(|msg| msg.role.as_str()).unwrap_or("unknown");
+        println!();
+        output.subheader(&format!("#{} {} differs", i + 1, role));
+        
+        // Differing system/user turns explain why later responses diverge
+        if role != "assistant" {
+            output.warning(&format!("The {} turn differs, so later responses are not directly comparable", role));
+        }
+        
+        print_side('-', path_a, msg_a, output);
+        print_side('+', path_b, msg_b, output);
+        println!();
+    }
+    
+    eprintln!();
+    match first_divergence {
+        Some(turn) => output.info(&format!(
+            "{} differing message(s); conversations diverge at message #{}",
+            differences, turn
+        )),
+        None => output.success("Conversations are identical"),
+    }
+    
+    Ok(())
+}
+
+/// Print one side of a differing message
+fn print_side(sign: char, path: &str, msg: Option<&ChatMessage>, output: &OutputFormatter) {
+    match msg {
+        Some(msg) => {
+            output.diff_line(sign, &format!("[{}] {}:", path, msg.role));
+            for line in msg.content.lines() {
+                output.diff_line(sign, line);
+            }
+        }
+        None => output.diff_line(sign, &format!("[{}] (no message)", path)),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,78 @@
+/*!
+ * Import Command Implementation
+ * 
+ * Register an existing local model file with the server.
+ */
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use crate::cli::ImportCommand;
+use crate::commands::models::print_local_models_table;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::output::{OutputFormatter, format_bytes};
+use crate::utils::{create_client, check_server_health, timed};
+
+pub async fn handle(cmd: ImportCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    let client = create_client(config, None)?;
+    
+    // Validate the source before bothering the server
+    let source = PathBuf::from(&cmd.path);
+    if !source.exists() {
+        return Err(CliError::InvalidInput(format!("File not found: {}", source.display())).into());
+    }
+    
+    // The server may run with a different working directory, so send an absolute path
+    let source = source.canonicalize()
+        .with_context(|| format!("Failed to resolve path: {}", source.display()))?;
+    let size = std::fs::metadata(&source)
+        .with_context(|| format!("Failed to read file metadata: {}", source.display()))?
+        .len();
+    
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+    
+    output.header(&format!("Importing Model: {}", source.display()));
+    eprintln!();
+    
+    output.subheader("Import Configuration");
+    output.detail("Source", &source.display().to_string());
+    output.detail("Size", &format_bytes(size));
+    output.detail("Mode", if cmd.move_file { "Move" } else { "Copy" });
+    
+    if let Some(ref name) = cmd.name {
+        output.detail("Registry ID", name);
+    }
+    
+    eprintln!();
+    output.progress("Importing and detecting format");
+    
+    let import_request = lmoclient::models::ImportModelRequest {
+        path: source.display().to_string(),
+        move_file: cmd.move_file,
+        name: cmd.name.clone(),
+    };
+    
+    let local_model = match timed(&output, "import_model", client.import_model(import_request)).await {
+        Ok(local_model) => {
+            output.progress_done();
+            local_model
+        }
+        Err(e) => {
+            output.progress_failed(&e.to_string());
+            return Err(CliError::from(e).into());
+        }
+    };
+    
+    output.success(&format!("Model imported: {}", local_model.filename));
+    eprintln!();
+    
+    match &config.output_format[..] {
+        "json" | "yaml" => output.print(&local_model)?,
+        _ => print_local_models_table(&[&local_model], &[], output.no_color()),
+    }
+    
+    Ok(())
+}
\ No newline at end of file
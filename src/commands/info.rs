@@ -0,0 +1,127 @@
+/*!
+ * Info Command Implementation
+ * 
+ * Detailed view of a single local or remote model.
+ */
+
+use anyhow::Result;
+use lmoclient::models::LocalModelInfo;
+use lmoserver::shared_types::ModelInfo;
+
+use crate::cli::InfoCommand;
+use crate::commands::models::extract_model_info;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::model_cache::{CachePolicy, list_models_cached};
+use crate::model_ref::ModelRef;
+use crate::output::{OutputFormatter, format_bytes, format_number};
+use crate::utils::{create_client, check_server_health, timed};
+
+pub async fn handle(cmd: InfoCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    let client = create_client(config, None)?;
+    
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+    
+    let model_ref = ModelRef::parse(&cmd.model_id)?;
+    let structured = matches!(config.output_format.as_str(), "json" | "yaml");
+    
+    // Local models carry the richest metadata, so check them first
+    if !cmd.remote {
+        output.progress("Checking local models");
+        let local_response = timed(&output, "list_local_models", client.list_local_models()).await?;
+        output.progress_done();
+        
+        let local_match = local_response.models.iter()
+            .find(|m| m.filename.eq_ignore_ascii_case(&cmd.model_id))
+            .or_else(|| local_response.models.iter().find(|m| model_ref.matches_prefix(&m.filename)));
+        
+        if let Some(local_model) = local_match {
+            if structured {
+                output.print(local_model)?;
+            } else {
+                print_local_info(local_model, &output);
+            }
+            return Ok(());
+        }
+    }
+    
+    if !cmd.local {
+        output.progress("Fetching model registry");
+        let models_response = timed(&output, "list_models", list_models_cached(&client, config, CachePolicy::Use)).await?;
+        output.progress_done();
+        
+        if let Some(model) = models_response.models.iter().find(|m| m.id.eq_ignore_ascii_case(&model_ref.repo_id())) {
+            if structured {
+                output.print(model)?;
+            } else {
+                print_remote_info(model, &output);
+            }
+            return Ok(());
+        }
+    }
+    
+    Err(CliError::ModelNotFound(cmd.model_id).into())
+}
+
+/// Print every field of a registry entry
+fn print_remote_info(model: &ModelInfo, output: &OutputFormatter) {
+    output.header(&format!("Model: {}", model.id));
+    eprintln!();
+    
+    output.key_value("Author", model.author.as_deref().unwrap_or("Unknown"));
+    output.key_value("Downloads", &format_number(model.downloads));
+    output.key_value("Pipeline", model.pipeline_tag.as_deref().unwrap_or("Unknown"));
+    output.key_value("Library", model.library_name.as_deref().unwrap_or("Unknown"));
+    output.key_value("Created", &model.created_at);
+    output.key_value("Updated", &model.updated_at);
+    output.key_value("Tags", &join_or_none(&model.tags));
+    output.key_value("Supported Formats", &join_or_none(&model.supported_formats));
+    
+    eprintln!();
+    output.subheader(&format!("Files ({})", model.files.len()));
+    for file in &model.files {
+        let size = file.size_bytes.map(format_bytes).unwrap_or_else(|| "Unknown".to_string());
+        println!("  {:<12} {}", size, file.filename);
+    }
+}
+
+/// Print every field of a local model, including its full server metadata
+fn print_local_info(local_model: &LocalModelInfo, output: &OutputFormatter) {
+    let (format, backend, compat_icon, compat_text) = extract_model_info(local_model);
+    
+    output.header(&format!("Local Model: {}", local_model.filename));
+    eprintln!();
+    
+    output.key_value("Path", &local_model.path);
+    output.key_value("Size", &format_bytes(local_model.size_bytes));
+    output.key_value("Modified", &local_model.last_modified.to_rfc3339());
+    output.key_value("Loaded", if local_model.is_loaded { "Yes" } else { "No" });
+    output.key_value("Format", &format);
+    output.key_value("Backend", &backend);
+    output.key_value("Compatibility", &format!("{} {}", compat_icon, compat_text));
+    
+    if let Some(ref metadata) = local_model.metadata {
+        eprintln!();
+        output.subheader("Metadata");
+        
+        let mut keys: Vec<_> = metadata.keys().collect();
+        keys.sort();
+        for key in keys {
+            let value = match &metadata[key] {
+                serde_json::Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            output.key_value(key, &value);
+        }
+    }
+}
+
+fn join_or_none(values: &[String]) -> String {
+    if values.is_empty() {
+        "None".to_string()
+    } else {
+        values.join(", ")
+    }
+}
\ No newline at end of file
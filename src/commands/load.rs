@@ -5,40 +5,137 @@
  */
 
 use anyhow::Result;
+use std::time::{Duration, Instant};
+use tracing::debug;
 use crate::cli::LoadCommand;
 use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::model_cache::{CachePolicy, list_models_cached};
+use crate::model_ref::{ModelRef, quantization_from_filename};
 use crate::output::OutputFormatter;
-use crate::utils::{create_client, check_server_health};
+use crate::utils::{create_client, check_server_health, fetch_loaded_models, notify_completion, select_model, spinner, timed};
+use lmoclient::LmoClient;
+use lmoclient::models::LocalModelInfo;
 
 pub async fn handle(cmd: LoadCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
     let client = create_client(config, None)?;
     
     // Check server health first
-    check_server_health(&client, &output).await?;
+    check_server_health(&client, config.retries, &output).await?;
     
-    output.header(&format!("Loading Model: {}", cmd.model_id));
-    println!();
+    // clap guarantees a model ID unless --pick was given
+    let model_id = match cmd.model_id.clone() {
+        Some(model_id) => model_id,
+        None => select_model(&client, config.retries, &output).await?,
+    };
+    let model_ref = ModelRef::parse(&model_id)?;
+    
+    output.header(&format!("Loading Model: {}", model_ref));
+    eprintln!();
+    
+    // Reloading an existing instance may disrupt anyone using it, so require confirmation
+    let loaded_models = timed(&output, "loaded_models", fetch_loaded_models(&client, config.retries)).await.unwrap_or_default();
+    let existing: Vec<_> = loaded_models.iter()
+        .filter(|m| model_ref.matches_prefix(&m.model_id))
+        .collect();
+    
+    if !existing.is_empty() {
+        output.subheader("Already Loaded");
+        for instance in &existing {
+            output.detail("Instance ID", &instance.instance_id);
+            output.detail("Status", &instance.status.to_string());
+            output.detail("Context Size", &instance.context_size
+                .map(|size| size.to_string())
+                .unwrap_or_else(|| "Unknown".to_string()));
+        }
+        
+        eprintln!();
+        output.subheader("Requested");
+        output.detail("Filename", cmd.filename.as_deref().unwrap_or("Auto"));
+        let or_default = |value: Option<String>| value.unwrap_or_else(|| "Server default".to_string());
+        output.detail("Context Size", &or_default(cmd.context_size.map(|size| size.to_string())));
+        output.detail("GPU Layers", &or_default(cmd.gpu_layers.map(|layers| layers.to_string())));
+        output.detail("Max Memory", &or_default(cmd.max_memory.map(|gb| format!("{} GB", gb))));
+        eprintln!();
+        
+        if !(cmd.force || cmd.yes) {
+            output.info(&format!(
+                "Model {} is already loaded as {}. Use --force or --yes to reload it.",
+                model_ref,
+                existing[0].instance_id
+            ));
+            return Ok(());
+        }
+        
+        output.warning("Reloading will replace the existing instance");
+    }
     
-    // Note: Model verification is handled by the server's Universal Model Engine
-    // which will search both HuggingFace registry and local repository
+    // Prefer locally downloaded models so loading works offline and skips the
+    // remote registry round-trip entirely
+    output.progress("Checking local models");
+    let local_match = match timed(&output, "list_local_models", client.list_local_models()).await {
+        Ok(local_response) => {
+            output.progress_done();
+            Ok(resolve_local_models(local_response.models, &model_ref, cmd.filename.as_deref())
+                .into_iter()
+                .next())
+        }
+        Err(e) => {
+            debug!("Failed to list local models: {}", e);
+            if !output.is_quiet() {
+                output.progress_failed(&e.to_string());
+            }
+            Err(e)
+        }
+    };
+    
+    if let Ok(Some(ref local_model)) = local_match {
+        output.info(&format!("Found local model: {}", local_model.filename));
+    } else {
+        // Not downloaded yet (or unknown) - fall back to verifying against the remote registry
+        output.progress("Verifying model in registry");
+        match timed(&output, "list_models", list_models_cached(&client, config, CachePolicy::Use)).await {
+            Ok(models_response) => {
+                output.progress_done();
+                
+                if !models_response.models.iter().any(|m| m.id.eq_ignore_ascii_case(&model_ref.repo_id())) {
+                    output.warning(&format!("Model '{}' was not found locally or in the registry", model_ref));
+                    output.info("Attempting to load anyway - the server may still be able to resolve it");
+                }
+            }
+            Err(e) => {
+                // Offline or air-gapped: the registry being unreachable shouldn't block a load
+                if !output.is_quiet() {
+                    output.progress_failed(&e.to_string());
+                }
+                let reason = if local_match.is_ok() {
+                    "it is not downloaded and the registry is unreachable"
+                } else {
+                    "neither local models nor the registry could be checked"
+                };
+                output.warning(&format!("Could not confirm that model '{}' exists: {}", model_ref, reason));
+                output.info("Attempting to load anyway - the server may still be able to resolve it");
+            }
+        }
+    }
     
     // Attempt to load the model
-    println!();
+    eprintln!();
     output.progress("Sending load request to server");
     
     let load_request = lmoclient::models::LoadModelRequest {
-        model_id: cmd.model_id.clone(),
+        model_id: model_id.clone(),
         filename: cmd.filename.clone(),
         config: Some(lmoclient::models::LoadModelConfig {
-            max_memory_gb: None,
-            gpu_layers: None,
-            context_size: None,
-            force_reload: cmd.force,
+            max_memory_gb: cmd.max_memory,
+            gpu_layers: cmd.gpu_layers,
+            context_size: cmd.context_size,
+            force_reload: cmd.force || !existing.is_empty(),
         }),
     };
     
-    let result = client.load_model(load_request).await;
+    let result = timed(&output, "load_model", client.load_model(load_request)).await;
     output.progress_done();
     
     match result {
@@ -46,8 +143,13 @@ pub async fn handle(cmd: LoadCommand, config: &CliConfig) -> Result<()> {
             if response.success {
                 output.success(&format!("✓ Model load initiated: {}", response.model_id));
                 
-                if let Some(instance_id) = response.instance_id {
-                    output.key_value("Instance ID", &instance_id);
+                // With --wait, the notification reports how the load ended instead
+                if cmd.notify && !cmd.wait {
+                    notify_completion("Model load initiated", &response.model_id, true);
+                }
+                
+                if let Some(ref instance_id) = response.instance_id {
+                    output.key_value("Instance ID", instance_id);
                 }
                 
                 if let Some(duration) = response.duration_ms {
@@ -56,7 +158,7 @@ pub async fn handle(cmd: LoadCommand, config: &CliConfig) -> Result<()> {
                 
                 if let Some(ref metadata) = response.metadata {
                     if let Some(status) = metadata.get("integration_status") {
-                        println!();
+                        eprintln!();
                         output.info(&format!("Status: {}", status));
                     }
                     
@@ -71,27 +173,159 @@ pub async fn handle(cmd: LoadCommand, config: &CliConfig) -> Result<()> {
                         }
                     }
                 }
+                
+                if cmd.wait {
+                    let Some(ref instance_id) = response.instance_id else {
+                        return Err(CliError::ServerError(
+                            "Server returned no instance ID; cannot wait for the model to load".to_string()
+                        ).into());
+                    };
+                    
+                    eprintln!();
+                    let waited = wait_until_loaded(&client, config.retries, instance_id, Duration::from_secs(cmd.wait_timeout), &output).await;
+                    
+                    if cmd.notify {
+                        match waited {
+                            Ok(()) => notify_completion("Model loaded", &response.model_id, true),
+                            Err(ref e) => notify_completion("Model load failed", &format!("{}: {}", model_ref, e), false),
+                        }
+                    }
+                    
+                    waited?;
+                    output.success(&format!("Model is ready: {}", response.model_id));
+                }
             } else {
                 output.warning(&format!("Model load request failed: {}", response.message));
                 
+                if cmd.notify {
+                    notify_completion("Model load failed", &format!("{}: {}", model_ref, response.message), false);
+                }
+                
                 // Show what was attempted
-                println!();
+                eprintln!();
                 output.subheader("Attempted Load Operation");
-                output.key_value("Model ID", &cmd.model_id);
+                output.detail("Model ID", &model_id);
                 
                 if let Some(ref filename) = cmd.filename {
-                    output.key_value("Specific File", filename);
+                    output.detail("Specific File", filename);
+                }
+                
+                if let Some(gpu_layers) = cmd.gpu_layers {
+                    output.detail("GPU Layers", &gpu_layers.to_string());
+                }
+                
+                if let Some(context_size) = cmd.context_size {
+                    output.detail("Context Size", &context_size.to_string());
+                }
+                
+                if let Some(max_memory) = cmd.max_memory {
+                    output.detail("Max Memory", &format!("{} GB", max_memory));
                 }
                 
                 if cmd.force {
-                    output.key_value("Force Reload", "Yes");
+                    output.detail("Force Reload", "Yes");
                 }
             }
         },
         Err(e) => {
             output.warning(&format!("Failed to communicate with server: {}", e));
+            
+            if cmd.notify {
+                notify_completion("Model load failed", &format!("{}: {}", model_ref, e), false);
+            }
         }
     }
     
     Ok(())
+}
+
+/// Local models a reference resolves to, best match first; `load` uses the first.
+/// An exact filename match ranks first, then a matching quantization, then any
+/// other file under the reference; ties keep the server's order.
+pub fn resolve_local_models(
+    local_models: Vec<LocalModelInfo>,
+    model_ref: &ModelRef,
+    filename: Option<&str>,
+) -> Vec<LocalModelInfo> {
+    let mut candidates: Vec<LocalModelInfo> = local_models
+        .into_iter()
+        .filter(|m| model_ref.matches_prefix(&m.filename))
+        .filter(|m| filename.is_none_or(|name| m.filename == name || m.filename.rsplit('/').next() == Some(name)))
+        .collect();
+    
+    candidates.sort_by_key(|m| {
+        let is_exact = model_ref.matches_exact(&m.filename) || filename.is_some_and(|name| m.filename == name);
+        let has_quant = model_ref.quant.as_ref().is_some_and(|quant| {
+            quantization_from_filename(&m.filename).is_some_and(|found| found.eq_ignore_ascii_case(quant))
+        });
+        
+        match (is_exact, has_quant) {
+            (true, _) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+        }
+    });
+    
+    candidates
+}
+
+/// How often the server is polled while waiting for a model to load
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Poll the loaded models until `instance_id` reports loaded or failed, showing
+/// a spinner with the elapsed time. Errors on failure, if the instance vanishes
+/// after it appeared, or after `timeout`.
+async fn wait_until_loaded(
+    client: &LmoClient,
+    retries: u32,
+    instance_id: &str,
+    timeout: Duration,
+    output: &OutputFormatter,
+) -> Result<()> {
+    let spinner = spinner(output, "{spinner:.green} Waiting for model to load [{elapsed_precise}] {msg}");
+    
+    let started = Instant::now();
+    let mut seen = false;
+    loop {
+        // A transient polling error is not a load failure; keep waiting
+        match fetch_loaded_models(client, retries).await {
+            Ok(models) => match models.iter().find(|m| m.instance_id == instance_id) {
+                Some(instance) => {
+                    seen = true;
+                    let status = instance.status.to_string();
+                    match status.to_lowercase().as_str() {
+                        "loaded" | "ready" => {
+                            spinner.finish_and_clear();
+                            return Ok(());
+                        }
+                        "failed" | "error" => {
+                            spinner.abandon_with_message(status.clone());
+                            return Err(CliError::CommandError(format!(
+                                "Model instance {} failed to load", instance_id
+                            )).into());
+                        }
+                        _ => spinner.set_message(status),
+                    }
+                }
+                // The server drops an instance whose load failed
+                None if seen => {
+                    spinner.abandon_with_message("unloaded");
+                    return Err(CliError::CommandError(format!(
+                        "Model instance {} disappeared before it finished loading", instance_id
+                    )).into());
+                }
+                None => spinner.set_message("pending"),
+            },
+            Err(e) => spinner.set_message(format!("server error: {}", e)),
+        }
+        
+        if started.elapsed() >= timeout {
+            spinner.abandon_with_message("timed out");
+            return Err(CliError::CommandError(format!(
+                "Model instance {} was not ready after {}s", instance_id, timeout.as_secs()
+            )).into());
+        }
+        
+        tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+    }
 }
\ No newline at end of file
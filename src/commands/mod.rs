@@ -11,4 +11,14 @@ pub mod unload;
 pub mod status;
 pub mod config;
 pub mod health;
-pub mod download;
\ No newline at end of file
+pub mod download;
+pub mod scan;
+pub mod import;
+pub mod history;
+pub mod resolve;
+pub mod info;
+pub mod completions;
+pub mod embeddings;
+pub mod tokenize;
+pub mod complete;
+pub mod dashboard;
\ No newline at end of file
@@ -4,25 +4,51 @@
  * List and search available models.
  */
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use colored::{Color, Colorize};
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::debug;
+use unicode_width::UnicodeWidthStr;
+
 use crate::cli::ModelsCommand;
 use crate::config::CliConfig;
-use crate::output::{OutputFormatter, format_number, truncate_text};
-use crate::utils::{create_client, check_server_health};
+use crate::error::CliError;
+use crate::model_cache::{CachePolicy, list_models_cached};
+use crate::model_ref::ModelRef;
+use crate::output::{OutputFormatter, format_bytes, format_number, truncate_text};
+use crate::utils::{create_client, check_server_health, timed};
 
 pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
     let client = create_client(config, None)?;
     
+    // Reject unknown column names before talking to the server
+    let mut columns = resolve_columns(&cmd.columns, cmd.local)?;
+    if cmd.downloaded && cmd.columns.is_empty() {
+        columns.push("local");
+    }
+    
     // Check server health first
-    check_server_health(&client, &output).await?;
+    check_server_health(&client, config.retries, &output).await?;
     
     output.progress("Fetching models");
     
+    let cache_policy = CachePolicy::from_flags(cmd.no_cache, cmd.refresh_cache);
+    
     // Fetch models with filters (local or remote)
+    let mut server_filtered = false;
     let (models_response, local_models_response) = if cmd.local {
         // Get local models - preserve both formats for enhanced display
-        let local_response = client.list_local_models().await?;
+        let mut local_response = timed(&output, "list_local_models", client.list_local_models()).await?;
+        
+        // Size filters only apply to local models, which know their size
+        if let Some(min_size) = cmd.min_size {
+            local_response.models.retain(|m| m.size_bytes >= min_size);
+        }
+        if let Some(max_size) = cmd.max_size {
+            local_response.models.retain(|m| m.size_bytes <= max_size);
+        }
         
         // Convert local models to ModelInfo format for filtering compatibility
         let models: Vec<lmoserver::shared_types::ModelInfo> = local_response.models.iter().map(|local_model| {
@@ -36,7 +62,7 @@ pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
                 pipeline_tag: None,
                 library_name: None,
                 files: vec![], // Local models don't have file info in this format
-                supported_formats: vec![], // Could be populated later
+                supported_formats: vec![extract_model_info(local_model).0], // Detected format
             }
         }).collect();
         
@@ -47,29 +73,66 @@ pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
         };
         
         (response, Some(local_response))
+    } else if cmd.search.is_some() || cmd.author.is_some() || cmd.pipeline.is_some() {
+        // Let the server search so matches beyond its default page aren't missed
+        let search_params = lmoclient::models::ModelSearchParams {
+            search: cmd.search.clone(),
+            author: cmd.author.clone(),
+            pipeline_tag: cmd.pipeline.clone(),
+        };
+        
+        match timed(&output, "search_models", client.search_models(search_params)).await {
+            Ok(response) => {
+                server_filtered = true;
+                (response, None)
+            }
+            Err(e) => {
+                debug!("Server-side search unavailable ({}), filtering client-side", e);
+                (timed(&output, "list_models", list_models_cached(&client, config, cache_policy)).await?, None)
+            }
+        }
     } else {
         // Get remote models from HuggingFace
-        (client.list_models().await?, None)
+        (timed(&output, "list_models", list_models_cached(&client, config, cache_policy)).await?, None)
     };
     
     output.progress_done();
     
-    if models_response.models.is_empty() {
+    if models_response.models.is_empty() && !cmd.count_only {
         output.warning("No models found matching the criteria");
-        return Ok(());
+        return if cmd.fail_on_empty { Err(no_matches_error()) } else { Ok(()) };
     }
     
-    // Apply client-side filtering and sorting
-    let mut models = models_response.models;
+    // Apply client-side filtering and sorting; converted local rows keep the
+    // index of the local model they came from
+    let mut models: Vec<(lmoserver::shared_types::ModelInfo, Option<usize>)> = models_response.models.into_iter()
+        .enumerate()
+        .map(|(index, model)| (model, local_models_response.is_some().then_some(index)))
+        .collect();
+    
+    // Cross-reference the registry with the model directory
+    let mut local_presence: HashMap<String, &'static str> = HashMap::new();
+    if cmd.downloaded {
+        let local_models = timed(&output, "list_local_models", client.list_local_models()).await?.models;
+        for (model, _) in &models {
+            if let Some(presence) = local_presence_of(model, &local_models) {
+                local_presence.insert(model.id.clone(), presence);
+            }
+        }
+        models.retain(|(m, _)| local_presence.contains_key(&m.id));
+    }
     
     // Filter by search term
-    if let Some(ref search) = cmd.search {
-        models.retain(|m| m.id.to_lowercase().contains(&search.to_lowercase()));
+    if let Some(search) = cmd.search.as_ref().filter(|_| !server_filtered) {
+        match ModelRef::parse(search) {
+            Ok(search_ref) => models.retain(|(m, _)| search_ref.matches_fuzzy(&m.id)),
+            Err(_) => models.retain(|(m, _)| m.id.to_lowercase().contains(&search.to_lowercase())),
+        }
     }
     
     // Filter by author
-    if let Some(ref author) = cmd.author {
-        models.retain(|m| {
+    if let Some(author) = cmd.author.as_ref().filter(|_| !server_filtered) {
+        models.retain(|(m, _)| {
             m.author.as_ref()
                 .map(|a| a.to_lowercase().contains(&author.to_lowercase()))
                 .unwrap_or(false)
@@ -79,7 +142,7 @@ pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
     // Filter by tags
     if let Some(ref tags) = cmd.tags {
         let search_tags: Vec<&str> = tags.split(',').map(|t| t.trim()).collect();
-        models.retain(|m| {
+        models.retain(|(m, _)| {
             search_tags.iter().any(|tag| {
                 m.tags.iter().any(|t| t.to_lowercase().contains(&tag.to_lowercase()))
             })
@@ -87,42 +150,147 @@ pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
     }
     
     // Filter by pipeline
-    if let Some(ref pipeline) = cmd.pipeline {
-        models.retain(|m| {
+    if let Some(pipeline) = cmd.pipeline.as_ref().filter(|_| !server_filtered) {
+        models.retain(|(m, _)| {
             m.pipeline_tag.as_ref()
                 .map(|p| p.to_lowercase().contains(&pipeline.to_lowercase()))
                 .unwrap_or(false)
         });
     }
     
+    // Filter by popularity (local models have no download counts)
+    if let Some(min_downloads) = cmd.min_downloads.filter(|_| !cmd.local) {
+        models.retain(|(m, _)| m.downloads >= min_downloads);
+    }
+    
+    // Filter by format (detected format for local models)
+    if let Some(ref format) = cmd.format {
+        let format = format.to_lowercase();
+        models.retain(|(m, _)| {
+            m.supported_formats.iter().any(|f| f.to_lowercase().contains(&format))
+        });
+    }
+    
+    // Only the number of matches was requested
+    if cmd.count_only {
+        match &config.output_format[..] {
+            "json" | "yaml" => println!("{}", models.len()),
+            _ => println!("{} model(s) match the given filters", models.len()),
+        }
+        
+        return if cmd.fail_on_empty && models.is_empty() { Err(no_matches_error()) } else { Ok(()) };
+    }
+    
+    if models.is_empty() && cmd.fail_on_empty {
+        output.warning("No models found matching the criteria");
+        return Err(no_matches_error());
+    }
+    
     // Sort models
     match cmd.sort.as_str() {
         "downloads" => {
             if cmd.direction == "asc" {
-                models.sort_by(|a, b| a.downloads.cmp(&b.downloads));
+                models.sort_by(|(a, _), (b, _)| a.downloads.cmp(&b.downloads));
             } else {
-                models.sort_by(|a, b| b.downloads.cmp(&a.downloads));
+                models.sort_by(|(a, _), (b, _)| b.downloads.cmp(&a.downloads));
             }
         }
         "author" => {
             if cmd.direction == "asc" {
-                models.sort_by(|a, b| a.author.cmp(&b.author));
+                models.sort_by(|(a, _), (b, _)| a.author.cmp(&b.author));
             } else {
-                models.sort_by(|a, b| b.author.cmp(&a.author));
+                models.sort_by(|(a, _), (b, _)| b.author.cmp(&a.author));
             }
         }
         "created" => {
             if cmd.direction == "asc" {
-                models.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+                models.sort_by(|(a, _), (b, _)| a.created_at.cmp(&b.created_at));
+            } else {
+                models.sort_by(|(a, _), (b, _)| b.created_at.cmp(&a.created_at));
+            }
+        }
+        "name" => {
+            if cmd.direction == "asc" {
+                models.sort_by_key(|(m, _)| m.id.to_lowercase());
+            } else {
+                models.sort_by_key(|(m, _)| std::cmp::Reverse(m.id.to_lowercase()));
+            }
+        }
+        "size" => {
+            // Local models know their size; remote sizes add up the listed files
+            let size_of = |(m, local_index): &(lmoserver::shared_types::ModelInfo, Option<usize>)| -> u64 {
+                match (local_models_response.as_ref(), local_index) {
+                    (Some(local_response), Some(index)) => local_response.models[*index].size_bytes,
+                    _ => m.files.iter().filter_map(|f| f.size_bytes).sum(),
+                }
+            };
+            
+            if cmd.direction == "asc" {
+                models.sort_by_key(size_of);
             } else {
-                models.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                models.sort_by_key(|entry| std::cmp::Reverse(size_of(entry)));
             }
         }
         _ => {} // Keep original order
     }
     
-    // Limit results
-    models.truncate(cmd.limit as usize);
+    // Page or limit results
+    let matched = models.len();
+    let page_size = cmd.page_size.unwrap_or(cmd.limit).max(1) as usize;
+    let total_pages = matched.div_ceil(page_size).max(1);
+    
+    if let Some(page) = cmd.page {
+        models = models.into_iter()
+            .skip((page as usize - 1) * page_size)
+            .take(page_size)
+            .collect();
+        
+        if models.is_empty() {
+            output.warning(&format!("Page {} is empty; there are {} page(s) of results", page, total_pages));
+            return Ok(());
+        }
+    } else {
+        models.truncate(cmd.limit as usize);
+    }
+    
+    let (models, local_indices): (Vec<_>, Vec<_>) = models.into_iter().unzip();
+    
+    // Local entries in the filtered, sorted order of `models`
+    let shown_local: Vec<&lmoclient::models::LocalModelInfo> = match local_models_response {
+        Some(ref local_response) => local_indices.iter()
+            .flatten()
+            .map(|&index| &local_response.models[index])
+            .collect(),
+        None => vec![],
+    };
+    
+    if let Some(ref path) = cmd.csv {
+        if cmd.local {
+            write_local_models_csv(path, &shown_local)?;
+        } else {
+            write_models_csv(path, &models)?;
+        }
+        output.success(&format!("Wrote {} model(s) to {}", models.len(), path));
+        
+        if !cmd.also_stdout {
+            return Ok(());
+        }
+    }
+    
+    // One object per line for streaming consumers, with no header or footer;
+    // local models keep their path, size and metadata
+    if cmd.json_lines {
+        if cmd.local {
+            for local_model in &shown_local {
+                println!("{}", serde_json::to_string(local_model)?);
+            }
+        } else {
+            for model in &models {
+                println!("{}", serde_json::to_string(model)?);
+            }
+        }
+        return Ok(());
+    }
     
     // Display results
     let title = if cmd.local {
@@ -131,7 +299,7 @@ pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
         format!("Available Models ({} found)", models.len())
     };
     output.header(&title);
-    println!();
+    eprintln!();
     
     match &config.output_format[..] {
         "json" => {
@@ -141,96 +309,320 @@ pub async fn handle(cmd: ModelsCommand, config: &CliConfig) -> Result<()> {
             output.print(&models)?;
         }
         _ => {
-            // Table format - adjust headers based on local vs remote
+            // Table format - adjust columns based on local vs remote
             if cmd.local {
-                // Enhanced local models display with compatibility information
-                println!("{:<40} {:<10} {:<12} {:<12} {:<3} {:<30} {:<12}", 
-                    "Model ID", "Format", "Backend", "Size", "✓", "Compatibility", "Status");
-                println!("{}", "-".repeat(119));
-                
-                if let Some(ref local_response) = local_models_response {
-                    for local_model in &local_response.models {
-                        let (format, backend, compat_icon, compat_text) = extract_model_info(local_model);
-                        let size = format_bytes(local_model.size_bytes);
-                        let status = if local_model.is_loaded { "Loaded" } else { "Available" };
-                        
-                        println!("{:<40} {:<10} {:<12} {:<12} {:<3} {:<30} {:<12}", 
-                            truncate_text(&local_model.filename, 40),
-                            format,
-                            backend,
-                            size,
-                            compat_icon,
-                            truncate_text(&compat_text, 30),
-                            status
-                        );
-                    }
+                if local_models_response.is_some() {
+                    print_local_models_columns(&shown_local, &columns, &cmd.show_metadata, output.no_color());
                 } else {
                     // Fallback to basic display if local_models_response is not available
-                    for model in &models {
-                        println!("{:<40} {:<10} {:<12} {:<12} {:<3} {:<30} {:<12}", 
-                            truncate_text(&model.id, 40),
-                            "Unknown",
-                            "Auto",
-                            "N/A",
-                            "❓",
-                            "Unknown",
-                            "Available"
-                        );
-                    }
+                    let rows: Vec<Vec<String>> = models.iter()
+                        .map(|model| columns.iter()
+                            .map(|column| if *column == "filename" { model.id.clone() } else { "Unknown".to_string() })
+                            .collect())
+                        .collect();
+                    print_table(&column_headers(&columns), &rows);
                 }
             } else {
-                // Remote models display
-                println!("{:<40} {:<20} {:<15} {:<20} {:<30}", 
-                    "Model ID", "Author", "Downloads", "Pipeline", "Tags");
-                println!("{}", "-".repeat(125));
-                
-                for model in &models {
-                    let author = model.author.as_deref().unwrap_or("Unknown");
-                    let pipeline = model.pipeline_tag.as_deref().unwrap_or("Unknown");
-                    let tags = if model.tags.is_empty() {
-                        "None".to_string()
-                    } else {
-                        truncate_text(&model.tags.join(", "), 30)
-                    };
-                    
-                    println!("{:<40} {:<20} {:<15} {:<20} {:<30}", 
-                        truncate_text(&model.id, 40),
-                        truncate_text(author, 20),
-                        format_number(model.downloads),
-                        truncate_text(pipeline, 20),
-                        tags
-                    );
-                }
+                let rows: Vec<Vec<String>> = models.iter()
+                    .map(|model| {
+                        let presence = local_presence.get(&model.id).copied();
+                        columns.iter().map(|column| remote_cell(model, column, presence)).collect()
+                    })
+                    .collect();
+                print_table(&column_headers(&columns), &rows);
             }
         }
     }
     
-    println!();
-    output.info(&format!("Showing {} of {} total models", models.len(), models_response.total.unwrap_or(models.len() as u32)));
+    eprintln!();
+    match cmd.page {
+        Some(page) if (page as usize) < total_pages => output.info(&format!(
+            "Page {}/{} ({} matching models) — use --page {} for more",
+            page, total_pages, matched, page + 1
+        )),
+        Some(page) => output.info(&format!("Page {}/{} ({} matching models)", page, total_pages, matched)),
+        None => output.info(&format!("Showing {} of {} total models", models.len(), models_response.total.unwrap_or(models.len() as u32))),
+    }
+    
+    Ok(())
+}
+
+/// Columns of the remote models table; the first five are shown by default
+const REMOTE_COLUMNS: &[&str] = &["id", "author", "downloads", "pipeline", "tags", "library", "formats", "created", "updated", "local"];
+const REMOTE_DEFAULT_COLUMNS: usize = 5;
+
+/// Columns of the local models table; the first seven are shown by default
+const LOCAL_COLUMNS: &[&str] = &["filename", "format", "backend", "size", "compat", "compatibility", "status", "path", "modified"];
+const LOCAL_DEFAULT_COLUMNS: usize = 7;
+
+/// Widest a table column may grow before its cells are truncated
+const MAX_COLUMN_WIDTH: usize = 50;
+
+/// Validate --columns against the columns of the current mode, or fall back to the defaults
+fn resolve_columns(requested: &[String], local: bool) -> Result<Vec<&'static str>> {
+    let (valid, default_count) = if local {
+        (LOCAL_COLUMNS, LOCAL_DEFAULT_COLUMNS)
+    } else {
+        (REMOTE_COLUMNS, REMOTE_DEFAULT_COLUMNS)
+    };
+    
+    if requested.is_empty() {
+        return Ok(valid[..default_count].to_vec());
+    }
+    
+    requested.iter()
+        .map(|name| {
+            let name = match name.trim().to_lowercase() {
+                // Local rows are keyed by filename; accept the remote name too
+                name if local && name == "id" => "filename".to_string(),
+                name => name,
+            };
+            valid.iter().copied().find(|column| *column == name).ok_or_else(|| CliError::InvalidInput(format!(
+                "Unknown column '{}' (valid: {})", name, valid.join(", ")
+            )).into())
+        })
+        .collect()
+}
+
+/// Header text for each column
+fn column_headers(columns: &[&str]) -> Vec<String> {
+    columns.iter()
+        .map(|column| match *column {
+            "id" | "filename" => "Model ID".to_string(),
+            "compat" => "✓".to_string(),
+            other => {
+                let mut chars = other.chars();
+                chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+            }
+        })
+        .collect()
+}
+
+/// Whether a remote model is downloaded: "✓ local" when every registry file is
+/// present and complete, "partial" when any is missing or smaller than the registry
+/// says, None if nothing is downloaded
+fn local_presence_of(
+    model: &lmoserver::shared_types::ModelInfo,
+    local_models: &[lmoclient::models::LocalModelInfo],
+) -> Option<&'static str> {
+    let model_ref = ModelRef::parse(&model.id).ok()?;
+    let local_files: Vec<_> = local_models.iter()
+        .filter(|local| model_ref.matches_prefix(&local.filename))
+        .collect();
+    
+    if local_files.is_empty() {
+        return None;
+    }
+    
+    // Compare whole path components so "model.gguf" doesn't match "other-model.gguf"
+    let complete = model.files.iter().all(|file| {
+        local_files.iter()
+            .find(|local| Path::new(&local.filename).ends_with(&file.filename))
+            .is_some_and(|local| file.size_bytes.is_none_or(|expected| local.size_bytes >= expected))
+    });
+    
+    Some(if complete { "✓ local" } else { "partial" })
+}
+
+/// Render one cell of the remote models table; `presence` fills the local column
+fn remote_cell(model: &lmoserver::shared_types::ModelInfo, column: &str, presence: Option<&str>) -> String {
+    let or_unknown = |value: Option<&String>| value.cloned().unwrap_or_else(|| "Unknown".to_string());
+    let or_none = |values: &[String]| if values.is_empty() { "None".to_string() } else { values.join(", ") };
+    
+    match column {
+        "id" => model.id.clone(),
+        "author" => or_unknown(model.author.as_ref()),
+        "downloads" => format_number(model.downloads),
+        "pipeline" => or_unknown(model.pipeline_tag.as_ref()),
+        "tags" => or_none(&model.tags),
+        "library" => or_unknown(model.library_name.as_ref()),
+        "formats" => or_none(&model.supported_formats),
+        "created" => model.created_at.clone(),
+        "updated" => model.updated_at.clone(),
+        "local" => presence.unwrap_or("-").to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+/// Print rows under their headers, sizing each column to its widest cell
+fn print_table(headers: &[String], rows: &[Vec<String>]) {
+    print_painted_table(headers, rows, |_, _, cell| cell);
+}
+
+/// Like `print_table`, but `paint(row, column, text)` may style each body cell;
+/// widths are measured on the plain text so escape codes don't skew alignment
+fn print_painted_table(headers: &[String], rows: &[Vec<String>], paint: impl Fn(usize, usize, String) -> String) {
+    let widths: Vec<usize> = headers.iter()
+        .enumerate()
+        .map(|(i, header)| {
+            rows.iter()
+                .map(|row| row[i].width())
+                .chain(std::iter::once(header.width()))
+                .max()
+                .unwrap_or(0)
+                .min(MAX_COLUMN_WIDTH)
+        })
+        .collect();
+    
+    let render = |cells: &[String], row: Option<usize>| {
+        let line: Vec<String> = cells.iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                let text = truncate_text(cell, *width);
+                let padding = " ".repeat(width.saturating_sub(text.width()));
+                match row {
+                    Some(row) => format!("{}{}", paint(row, column, text), padding),
+                    None => format!("{}{}", text, padding),
+                }
+            })
+            .collect();
+        line.join(" ").trim_end().to_string()
+    };
+    
+    println!("{}", render(headers, None));
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + widths.len().saturating_sub(1)));
+    for (index, row) in rows.iter().enumerate() {
+        println!("{}", render(row, Some(index)));
+    }
+}
+
+/// Error returned by --fail-on-empty when nothing matches
+fn no_matches_error() -> anyhow::Error {
+    CliError::ModelNotFound("No models matched the given filters".to_string()).into()
+}
+
+/// Write remote models as CSV (id, author, downloads, pipeline, tags)
+fn write_models_csv(path: &str, models: &[lmoserver::shared_types::ModelInfo]) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)
+        .with_context(|| format!("Failed to create CSV file: {}", path))?;
+    
+    writer.write_record(["id", "author", "downloads", "pipeline", "tags"])?;
+    for model in models {
+        writer.write_record([
+            model.id.as_str(),
+            model.author.as_deref().unwrap_or(""),
+            &model.downloads.to_string(),
+            model.pipeline_tag.as_deref().unwrap_or(""),
+            &model.tags.join(","),
+        ])?;
+    }
     
+    writer.flush()
+        .with_context(|| format!("Failed to write CSV file: {}", path))?;
     Ok(())
 }
 
-/// Format bytes into human readable format
-fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
-    
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
+/// Write local models as CSV (filename, format, backend, size_bytes, is_loaded)
+fn write_local_models_csv(path: &str, local_models: &[&lmoclient::models::LocalModelInfo]) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)
+        .with_context(|| format!("Failed to create CSV file: {}", path))?;
+    
+    writer.write_record(["filename", "format", "backend", "size_bytes", "is_loaded"])?;
+    for local_model in local_models {
+        let (format, backend, _, _) = extract_model_info(local_model);
+        writer.write_record([
+            local_model.filename.as_str(),
+            &format,
+            &backend,
+            &local_model.size_bytes.to_string(),
+            &local_model.is_loaded.to_string(),
+        ])?;
     }
     
-    if unit_index == 0 {
-        format!("{} {}", bytes, UNITS[unit_index])
+    writer.flush()
+        .with_context(|| format!("Failed to write CSV file: {}", path))?;
+    Ok(())
+}
+
+/// Print local models with format, backend, and compatibility information,
+/// plus one extra column per requested metadata key
+pub fn print_local_models_table(local_models: &[&lmoclient::models::LocalModelInfo], metadata_keys: &[String], no_color: bool) {
+    print_local_models_columns(local_models, &LOCAL_COLUMNS[..LOCAL_DEFAULT_COLUMNS], metadata_keys, no_color);
+}
+
+/// Print the chosen columns of the local models table, then the metadata columns
+fn print_local_models_columns(
+    local_models: &[&lmoclient::models::LocalModelInfo],
+    columns: &[&str],
+    metadata_keys: &[String],
+    no_color: bool,
+) {
+    let mut headers = column_headers(columns);
+    headers.extend(metadata_keys.iter().cloned());
+    
+    let rows: Vec<Vec<String>> = local_models.iter()
+        .map(|local_model| {
+            let (format, backend, compat_icon, compat_text) = extract_model_info(local_model);
+            
+            let mut row: Vec<String> = columns.iter()
+                .map(|column| match *column {
+                    "filename" => local_model.filename.clone(),
+                    "format" => format.clone(),
+                    "backend" => backend.clone(),
+                    "size" => format_bytes(local_model.size_bytes),
+                    "compat" => compat_icon.clone(),
+                    "compatibility" => compat_text.clone(),
+                    "status" => if local_model.is_loaded { "Loaded" } else { "Available" }.to_string(),
+                    "path" => local_model.path.clone(),
+                    "modified" => local_model.last_modified.format("%Y-%m-%d %H:%M").to_string(),
+                    _ => "-".to_string(),
+                })
+                .collect();
+            
+            row.extend(metadata_keys.iter().map(|key| metadata_value(local_model, key)));
+            row
+        })
+        .collect();
+    
+    // Color the compatibility cells by how confident the server is that the model will run
+    let colors: Vec<Option<Color>> = local_models.iter().map(|local_model| compatibility_color(local_model)).collect();
+    print_painted_table(&headers, &rows, |row, column, text| {
+        match (columns.get(column), colors[row]) {
+            (Some(&"compat") | Some(&"compatibility"), Some(color)) if !no_color => text.color(color).to_string(),
+            _ => text,
+        }
+    });
+}
+
+/// Green for confidently compatible models, yellow for universal or medium
+/// confidence, red for incompatible or low confidence; None without metadata
+fn compatibility_color(local_model: &lmoclient::models::LocalModelInfo) -> Option<Color> {
+    let metadata = local_model.metadata.as_ref()?;
+    
+    let text = metadata.get("compatibility_text")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_lowercase();
+    
+    let confidence = metadata.get("format_detection")
+        .and_then(|detection| detection.get("confidence"))
+        .and_then(|v| v.as_f64());
+    
+    if text.contains("incompatible") || text.contains("unsupported") || confidence.is_some_and(|c| c < 0.5) {
+        Some(Color::Red)
+    } else if text.contains("universal") || confidence.is_some_and(|c| c < 0.8) {
+        Some(Color::Yellow)
+    } else if text.contains("compatible") || confidence.is_some() {
+        Some(Color::Green)
     } else {
-        format!("{:.1} {}", size, UNITS[unit_index])
+        None
+    }
+}
+
+/// Look up an arbitrary metadata key and render it as a table cell ("-" when absent)
+fn metadata_value(local_model: &lmoclient::models::LocalModelInfo, key: &str) -> String {
+    let value = local_model.metadata.as_ref().and_then(|metadata| metadata.get(key));
+    
+    match value {
+        None | Some(serde_json::Value::Null) => "-".to_string(),
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
     }
 }
 
 /// Extract model format, backend, and compatibility info from server metadata
-fn extract_model_info(local_model: &lmoclient::models::LocalModelInfo) -> (String, String, String, String) {
+pub fn extract_model_info(local_model: &lmoclient::models::LocalModelInfo) -> (String, String, String, String) {
     if let Some(metadata) = &local_model.metadata {
         // Extract format from server metadata
         let format = metadata.get("format")
@@ -0,0 +1,86 @@
+/*!
+ * Resolve Command Implementation
+ * 
+ * Show which local model file a reference resolves to, using the same
+ * resolution as `load`, without loading anything.
+ */
+
+use anyhow::Result;
+
+use crate::cli::ResolveCommand;
+use crate::commands::load::resolve_local_models;
+use crate::commands::models::extract_model_info;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::model_ref::ModelRef;
+use crate::output::{OutputFormatter, format_bytes};
+use crate::utils::{create_client, check_server_health, timed};
+
+pub async fn handle(cmd: ResolveCommand, config: &CliConfig) -> Result<()> {
+    let mut config = config.clone();
+    if cmd.json {
+        config.output_format = "json".to_string();
+    }
+    
+    let output = OutputFormatter::new(&config, None, false);
+    let client = create_client(&config, None)?;
+    
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+    
+    let mut model_ref = ModelRef::parse(&cmd.model_id)?;
+    if cmd.quant.is_some() {
+        model_ref.quant = cmd.quant.clone();
+    }
+    
+    output.progress("Checking local models");
+    let local_response = timed(&output, "list_local_models", client.list_local_models()).await?;
+    output.progress_done();
+    
+    let candidates = resolve_local_models(local_response.models, &model_ref, cmd.filename.as_deref());
+    let Some(chosen) = candidates.first() else {
+        return Err(CliError::ModelNotFound(format!(
+            "'{}' does not resolve to any local model file", model_ref
+        )).into());
+    };
+    
+    let (format, backend, _, _) = extract_model_info(chosen);
+    
+    match &config.output_format[..] {
+        "json" | "yaml" => {
+            let resolved = serde_json::json!({
+                "reference": model_ref.to_string(),
+                "filename": chosen.filename,
+                "path": chosen.path,
+                "size_bytes": chosen.size_bytes,
+                "format": format,
+                "backend": backend,
+                "is_loaded": chosen.is_loaded,
+                "other_candidates": candidates[1..].iter().map(|m| &m.filename).collect::<Vec<_>>(),
+            });
+            output.print(&resolved)?;
+        }
+        _ => {
+            output.header(&format!("Resolved: {}", model_ref));
+            eprintln!();
+            
+            output.key_value("Filename", &chosen.filename);
+            output.key_value("Path", &chosen.path);
+            output.key_value("Size", &format_bytes(chosen.size_bytes));
+            output.key_value("Format", &format);
+            output.key_value("Backend", &backend);
+            output.key_value("Loaded", if chosen.is_loaded { "Yes" } else { "No" });
+            
+            if candidates.len() > 1 {
+                eprintln!();
+                output.subheader("Other Matching Files");
+                for candidate in &candidates[1..] {
+                    println!("  {}", candidate.filename);
+                }
+                output.info("Use --filename or --quant to pick a different file");
+            }
+        }
+    }
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+/*!
+ * Scan Command Implementation
+ *
+ * Compare the model directory on disk against the server's local registry.
+ */
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::cli::ScanCommand;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::output::{OutputFormatter, format_bytes};
+use crate::utils::{create_client, check_server_health, confirm_action, timed};
+
+pub async fn handle(cmd: ScanCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    let client = create_client(config, None)?;
+
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+
+    let model_dir = PathBuf::from(&cmd.model_dir);
+    if !model_dir.is_dir() {
+        return Err(CliError::InvalidInput(
+            format!("Model directory does not exist: {}", model_dir.display())
+        ).into());
+    }
+
+    output.header(&format!("Scanning Model Directory: {}", model_dir.display()));
+    eprintln!();
+
+    output.progress("Fetching registered local models");
+    let local_response = timed(&output, "list_local_models", client.list_local_models()).await?;
+    output.progress_done();
+
+    // Registry entries may be relative to the model directory
+    let registered: Vec<(PathBuf, &lmoclient::models::LocalModelInfo)> = local_response.models
+        .iter()
+        .map(|m| {
+            let path = PathBuf::from(&m.path);
+            let path = if path.is_absolute() { path } else { model_dir.join(path) };
+            (path, m)
+        })
+        .collect();
+
+    output.progress("Scanning files on disk");
+    let mut disk_files = Vec::new();
+    collect_files(&model_dir, &mut disk_files)
+        .with_context(|| format!("Failed to scan model directory: {}", model_dir.display()))?;
+    output.progress_done();
+
+    // Files on disk that no registry entry covers (entries may be single files or whole directories)
+    let orphans: Vec<(PathBuf, u64)> = disk_files
+        .into_iter()
+        .filter(|(file, _)| !registered.iter().any(|(path, _)| file.starts_with(path)))
+        .collect();
+
+    // Registry entries whose files are gone
+    let missing: Vec<&(PathBuf, &lmoclient::models::LocalModelInfo)> = registered
+        .iter()
+        .filter(|(path, _)| !path.exists())
+        .collect();
+
+    eprintln!();
+    output.subheader(&format!("Orphaned Files ({})", orphans.len()));
+    if orphans.is_empty() {
+        output.info("No orphaned files found");
+    } else {
+        for (file, size) in &orphans {
+            println!("{:<12} {}", format_bytes(*size), display_relative(file, &model_dir));
+        }
+    }
+
+    eprintln!();
+    output.subheader(&format!("Missing Registry Entries ({})", missing.len()));
+    if missing.is_empty() {
+        output.info("All registered models are present on disk");
+    } else {
+        for (path, model) in &missing {
+            println!("{:<12} {} ({})", format_bytes(model.size_bytes), model.filename, path.display());
+        }
+    }
+
+    let orphaned_bytes: u64 = orphans.iter().map(|(_, size)| size).sum();
+    eprintln!();
+    output.info(&format!(
+        "{} orphaned file(s) using {} • {} missing registry entr{}",
+        orphans.len(),
+        format_bytes(orphaned_bytes),
+        missing.len(),
+        if missing.len() == 1 { "y" } else { "ies" }
+    ));
+
+    if cmd.prune_orphans && !orphans.is_empty() {
+        let prompt = format!("Delete {} orphaned file(s) ({})?", orphans.len(), format_bytes(orphaned_bytes));
+        if !confirm_action(&prompt, false)? {
+            output.info("Pruning cancelled");
+            return Ok(());
+        }
+
+        let mut removed = 0;
+        for (file, _) in &orphans {
+            match std::fs::remove_file(file) {
+                Ok(_) => removed += 1,
+                Err(e) => output.warning(&format!("Failed to delete {}: {}", file.display(), e)),
+            }
+        }
+        output.success(&format!("Deleted {} of {} orphaned file(s)", removed, orphans.len()));
+    }
+
+    Ok(())
+}
+
+/// Recursively collect all files (and their sizes) below a directory
+fn collect_files(dir: &Path, files: &mut Vec<(PathBuf, u64)>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+
+        if metadata.is_dir() {
+            collect_files(&entry.path(), files)?;
+        } else if metadata.is_file() {
+            files.push((entry.path(), metadata.len()));
+        }
+    }
+    Ok(())
+}
+
+/// Show a path relative to the model directory when possible
+fn display_relative(path: &Path, base: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
\ No newline at end of file
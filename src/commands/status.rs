@@ -4,26 +4,57 @@
  * Show status of loaded models and server information.
  */
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute};
+use lmoclient::LmoClient;
+use lmoclient::models::LoadedModelInfo;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+use tokio::signal;
+use tracing::debug;
+
 use crate::cli::StatusCommand;
 use crate::config::CliConfig;
-use crate::output::{OutputFormatter, format_number};
-use crate::utils::{create_client, check_server_health, format_duration};
+use crate::error::CliError;
+use crate::model_ref::ModelRef;
+use crate::output::{OutputFormatter, fit_text, format_bytes, format_number};
+use crate::utils::{create_client, check_server_health, fetch_loaded_models, format_duration, retry_idempotent, timed};
 
 pub async fn handle(cmd: StatusCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
     let client = create_client(config, None)?;
     
+    // Watch mode rides out outages itself, so it skips the up-front health check
+    if let Some(refresh) = cmd.refresh {
+        return watch(&client, config.retries, &output, refresh, cmd.detailed).await;
+    }
+    
     // Check server health first
-    check_server_health(&client, &output).await?;
+    check_server_health(&client, config.retries, &output).await?;
+    
+    if let Some(ref model_id) = cmd.model {
+        return model_status(&client, model_id, config, &output).await;
+    }
+    
+    if matches!(config.output_format.as_str(), "json" | "yaml") {
+        return print_structured_status(&client, config.retries, &cmd, &output).await;
+    }
     
     if cmd.detailed {
         output.header("Server Status");
-        println!();
+        eprintln!();
         
-        // Get server health information
+        // Health and the model list are independent, so fetch them together
         output.progress("Getting server status");
-        let health = client.health().await?;
+        let (health, models_response) = tokio::try_join!(
+            async { timed(&output, "health", client.health()).await.context("Failed to get server health") },
+            async {
+                timed(&output, "list_models", retry_idempotent(config.retries, || client.list_models())).await
+                    .context("Failed to get model information")
+            },
+        )?;
         output.progress_done();
         
         output.key_value("Server Status", &health.status);
@@ -33,33 +64,33 @@ pub async fn handle(cmd: StatusCommand, config: &CliConfig) -> Result<()> {
         
         println!();
         
-        // Get available models count
-        output.progress("Getting model information");
-        let models_response = client.list_models().await?;
-        output.progress_done();
-        
         output.key_value("Available Models", &format_number(models_response.models.len() as u64));
         
         if let Some(total) = models_response.total {
             output.key_value("Total in Registry", &format_number(total as u64));
         }
         
-        println!();
+        eprintln!();
         
         // Model management status
         output.subheader("Model Management Status");
         output.key_value("Load/Unload Support", "Pending Universal Model Engine integration");
         output.key_value("Current Capability", "Model discovery and health monitoring");
         
-        println!();
+        eprintln!();
         output.info("ℹ Model loading features will be available once the server's Universal Model Engine system is fully integrated.");
         
     } else {
         // Simple status overview
         output.progress("Checking status");
         
-        let health = client.health().await?;
-        let models_response = client.list_models().await?;
+        let (health, models_response) = tokio::try_join!(
+            async { timed(&output, "health", client.health()).await.context("Failed to get server health") },
+            async {
+                timed(&output, "list_models", retry_idempotent(config.retries, || client.list_models())).await
+                    .context("Failed to get model information")
+            },
+        )?;
         
         output.progress_done();
         
@@ -77,20 +108,277 @@ pub async fn handle(cmd: StatusCommand, config: &CliConfig) -> Result<()> {
             models_response.models.len(),
             format_duration(health.uptime_seconds)
         ));
+    }
+    
+    // Loaded model instances, oldest first so paging is stable
+    let loaded_models = timed(&output, "loaded_models", fetch_loaded_models(&client, config.retries)).await?;
+    let page: Vec<_> = loaded_models.iter()
+        .skip(cmd.offset)
+        .take(cmd.limit.unwrap_or(usize::MAX))
+        .collect();
+    
+    eprintln!();
+    if loaded_models.is_empty() {
+        output.info("No models are currently loaded. Use 'lmo load <model>' to load one.");
+    } else if page.is_empty() {
+        output.info(&format!("No loaded models past offset {} ({} loaded)", cmd.offset, loaded_models.len()));
+    } else {
+        if page.len() == loaded_models.len() {
+            output.subheader(&format!("Loaded Models ({})", loaded_models.len()));
+        } else {
+            output.subheader(&format!(
+                "Loaded Models ({}-{} of {})",
+                cmd.offset + 1,
+                cmd.offset + page.len(),
+                loaded_models.len()
+            ));
+        }
+        for model in &page {
+            println!("{:<38} {} {}", model.instance_id, fit_text(&model.model_id, 40), model.status);
+        }
+    }
+    
+    if cmd.require_loaded && loaded_models.is_empty() {
+        return Err(CliError::NoModelsLoaded.into());
+    }
+    
+    Ok(())
+}
+
+/// Print health, registry summary and the requested page of loaded instances as one object
+async fn print_structured_status(client: &LmoClient, retries: u32, cmd: &StatusCommand, output: &OutputFormatter) -> Result<()> {
+    let (health, models_response, loaded_models) = tokio::try_join!(
+        async { timed(output, "health", client.health()).await.context("Failed to get server health") },
+        async {
+            timed(output, "list_models", retry_idempotent(retries, || client.list_models())).await
+                .context("Failed to get model information")
+        },
+        async {
+            timed(output, "loaded_models", fetch_loaded_models(client, retries)).await
+                .context("Failed to get loaded models")
+        },
+    )?;
+    let page: Vec<_> = loaded_models.iter()
+        .skip(cmd.offset)
+        .take(cmd.limit.unwrap_or(usize::MAX))
+        .collect();
+    
+    output.print(&serde_json::json!({
+        "health": health,
+        "models": {
+            "available": models_response.models.len(),
+            "total": models_response.total,
+        },
+        "loaded": page,
+        "count": loaded_models.len(),
+        "offset": cmd.offset,
+    }))?;
+    
+    if cmd.require_loaded && loaded_models.is_empty() {
+        return Err(CliError::NoModelsLoaded.into());
+    }
+    
+    Ok(())
+}
+
+/// Show every loaded instance of one model (matched by model id or instance id)
+async fn model_status(client: &LmoClient, model_id: &str, config: &CliConfig, output: &OutputFormatter) -> Result<()> {
+    let model_ref = ModelRef::parse(model_id)?;
+    let instances: Vec<LoadedModelInfo> = timed(output, "loaded_models", fetch_loaded_models(client, config.retries)).await?
+        .into_iter()
+        .filter(|m| m.instance_id == model_id || model_ref.matches_prefix(&m.model_id))
+        .collect();
+    
+    if instances.is_empty() {
+        output.warning(&format!("'{}' is not loaded", model_id));
+        output.info(&format!("Load it with 'lmo load {}'", model_id));
+        return Err(CliError::ModelNotFound(model_id.to_string()).into());
+    }
+    
+    if matches!(config.output_format.as_str(), "json" | "yaml") {
+        return output.print(&instances);
+    }
+    
+    output.header(&format!("Model Status: {}", model_id));
+    
+    for instance in &instances {
+        eprintln!();
+        output.subheader(&instance.instance_id);
+        output.key_value("Model ID", &instance.model_id);
+        output.key_value("Status", &instance.status.to_string());
+        output.key_value("Loaded At", &instance.loaded_at.to_rfc3339());
+        output.key_value("Memory Usage", &instance.memory_usage_bytes
+            .map(format_bytes)
+            .unwrap_or_else(|| "Unknown".to_string()));
+        output.key_value("Context Size", &instance.context_size
+            .map(|size| format_number(size as u64))
+            .unwrap_or_else(|| "Unknown".to_string()));
         
-        if cmd.refresh.is_some() {
-            output.info("Note: Watch mode not yet implemented. Use health command for monitoring.");
+        if let Some(ref metadata) = instance.metadata {
+            for (key, value) in metadata {
+                let value = match value {
+                    serde_json::Value::String(text) => text.clone(),
+                    other => other.to_string(),
+                };
+                output.key_value(key, &value);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+/// Longest wait between reconnect attempts in watch mode
+const WATCH_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Give up on a single watch-mode fetch after this long and treat it as a failure
+const WATCH_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outage length after which watch mode shows the "server unreachable" banner
+const WATCH_LONG_OUTAGE: Duration = Duration::from_secs(60);
+
+/// Everything shown in one watch-mode frame
+struct WatchFrame {
+    summary: String,
+    /// Server details shown with --detailed
+    details: Vec<(&'static str, String)>,
+    loaded_models: Vec<LoadedModelInfo>,
+    refreshed_at: DateTime<Local>,
+}
+
+/// Redraw the status every `refresh` seconds until Ctrl+C. Failed fetches keep
+/// the last good frame on screen, print the error beneath it and retry with
+/// backoff instead of exiting.
+async fn watch(client: &LmoClient, retries: u32, output: &OutputFormatter, refresh: u64, detailed: bool) -> Result<()> {
+    let interval = Duration::from_secs(refresh.max(1));
+    let ctrl_c = signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    
+    let mut last_frame: Option<WatchFrame> = None;
+    let mut failures: u32 = 0;
+    let mut outage_started: Option<Instant> = None;
+    
+    loop {
+        let fetched = tokio::select! {
+            result = tokio::time::timeout(WATCH_FETCH_TIMEOUT, fetch_watch_frame(client, retries, detailed)) => result,
+            _ = &mut ctrl_c => break,
+        };
+        
+        let delay = match fetched {
+            Ok(Ok(frame)) => {
+                if failures > 0 {
+                    debug!("Reconnected after {} failed refresh(es)", failures);
+                }
+                failures = 0;
+                outage_started = None;
+                
+                draw_watch_frame(&frame, interval, output)?;
+                last_frame = Some(frame);
+                interval
+            }
+            failed => {
+                let reason = match failed {
+                    Ok(Err(e)) => e.to_string(),
+                    _ => format!("timed out after {}s", WATCH_FETCH_TIMEOUT.as_secs()),
+                };
+                debug!("Status refresh failed: {}", reason);
+                failures += 1;
+                let outage = outage_started.get_or_insert_with(Instant::now).elapsed();
+                
+                // Back off exponentially from the refresh interval, capped
+                let delay = interval
+                    .saturating_mul(2u32.saturating_pow(failures.min(16)))
+                    .min(WATCH_MAX_BACKOFF);
+                
+                // Keep the last good frame visible underneath the notice
+                match last_frame {
+                    Some(ref frame) => draw_watch_frame(frame, interval, output)?,
+                    None => clear_screen()?,
+                }
+                
+                eprintln!();
+                output.error(&format!("Refresh failed: {}", reason));
+                if outage >= WATCH_LONG_OUTAGE {
+                    output.error(&format!("Server unreachable for {}m", outage.as_secs() / 60));
+                }
+                output.warning(&format!(
+                    "Reconnecting... (attempt {}, next retry in {}s)",
+                    failures,
+                    delay.as_secs()
+                ));
+                delay
+            }
+        };
+        
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = &mut ctrl_c => break,
+        }
+    }
+    
+    eprintln!();
+    output.info("Stopped watching");
+    
+    Ok(())
+}
+
+/// Fetch the data for one watch-mode frame
+async fn fetch_watch_frame(client: &LmoClient, retries: u32, detailed: bool) -> Result<WatchFrame> {
+    let (health, models_response, loaded_models) = tokio::try_join!(
+        async { client.health().await.context("Failed to get server health") },
+        async { retry_idempotent(retries, || client.list_models()).await.context("Failed to get model information") },
+        async { fetch_loaded_models(client, retries).await.context("Failed to get loaded models") },
+    )?;
+    
+    let summary = format!(
+        "Server is {} • {} models available • {} loaded • Uptime: {}",
+        health.status,
+        models_response.models.len(),
+        loaded_models.len(),
+        format_duration(health.uptime_seconds)
+    );
+    
+    let mut details = Vec::new();
+    if detailed {
+        details.push(("Server Version", health.server_version.clone()));
+        details.push(("Server URL", client.config().server_url.clone()));
+        if let Some(total) = models_response.total {
+            details.push(("Total in Registry", format_number(total as u64)));
         }
     }
     
-    // Handle specific model status
-    if let Some(model_id) = cmd.model {
+    Ok(WatchFrame { summary, details, loaded_models, refreshed_at: Local::now() })
+}
+
+/// Clear the terminal and draw a watch-mode frame
+fn draw_watch_frame(frame: &WatchFrame, interval: Duration, output: &OutputFormatter) -> Result<()> {
+    clear_screen()?;
+    
+    println!(
+        "Status (every {}s, Ctrl+C to stop) • Last refresh: {}",
+        interval.as_secs(),
+        frame.refreshed_at.format("%H:%M:%S")
+    );
+    println!();
+    println!("{}", frame.summary);
+    println!();
+    
+    if !frame.details.is_empty() {
+        for (key, value) in &frame.details {
+            output.key_value(key, value);
+        }
         println!();
-        output.warning(&format!(
-            "Model-specific status for '{}' not yet available. Model loading functionality is pending server integration.",
-            model_id
-        ));
     }
     
+    for model in &frame.loaded_models {
+        println!("{:<38} {} {}", model.instance_id, fit_text(&model.model_id, 40), model.status);
+    }
+    
+    io::stdout().flush()?;
+    Ok(())
+}
+
+fn clear_screen() -> Result<()> {
+    execute!(io::stdout(), terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+/*!
+ * Tokenize Command Implementation
+ * 
+ * Count and inspect the tokens a model's tokenizer produces for a text.
+ */
+
+use anyhow::Result;
+use lmoserver::shared_types::TokenizeRequest;
+
+use crate::cli::TokenizeCommand;
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::output::{OutputFormatter, format_number};
+use crate::utils::{create_client, check_server_health, read_input_text, timed};
+
+pub async fn handle(cmd: TokenizeCommand, config: &CliConfig) -> Result<()> {
+    let output = OutputFormatter::new(config, None, false);
+    
+    let model = cmd.model.clone()
+        .or_else(|| config.default_model.clone())
+        .ok_or_else(|| CliError::InvalidInput(
+            "No model given: pass --model or set default_model in the config".to_string()
+        ))?;
+    
+    let text = read_input_text(cmd.text.as_deref(), cmd.file.as_deref())?;
+    
+    let client = create_client(config, None)?;
+    
+    // Check server health first
+    check_server_health(&client, config.retries, &output).await?;
+    
+    output.progress("Tokenizing");
+    let response = timed(&output, "tokenize", client.tokenize(TokenizeRequest {
+        model: model.clone(),
+        text,
+    })).await?;
+    output.progress_done();
+    
+    if matches!(config.output_format.as_str(), "json" | "yaml") {
+        return output.print(&response);
+    }
+    
+    output.key_value("Model", &model);
+    output.key_value("Tokens", &format_number(response.tokens.len() as u64));
+    
+    if cmd.show_tokens {
+        eprintln!();
+        for (id, piece) in response.tokens.iter().zip(&response.pieces) {
+            // Debug formatting makes whitespace and control characters visible
+            println!("{:>8}  {:?}", id, piece);
+        }
+    }
+    
+    Ok(())
+}
\ No newline at end of file
@@ -5,29 +5,75 @@
  */
 
 use anyhow::Result;
+use lmoclient::LmoClient;
 use crate::cli::UnloadCommand;
 use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::model_ref::ModelRef;
 use crate::output::OutputFormatter;
-use crate::utils::{create_client, check_server_health};
+use crate::utils::{create_client, check_server_health, confirm, fetch_loaded_models, timed};
 
 pub async fn handle(cmd: UnloadCommand, config: &CliConfig) -> Result<()> {
     let output = OutputFormatter::new(config, None, false);
     let client = create_client(config, None)?;
     
     // Check server health first
-    check_server_health(&client, &output).await?;
+    check_server_health(&client, config.retries, &output).await?;
     
-    output.header(&format!("Unloading Model Instance: {}", cmd.instance_id));
-    println!();
+    // clap guarantees either an instance id or --model
+    let instance_ids = match (&cmd.instance_id, &cmd.model) {
+        (Some(instance_id), _) => vec![instance_id.clone()],
+        (None, Some(model_id)) => {
+            let model_ref = ModelRef::parse(model_id)?;
+            let instance_ids: Vec<String> = timed(&output, "loaded_models", fetch_loaded_models(&client, config.retries)).await?
+                .into_iter()
+                .filter(|m| model_ref.matches_prefix(&m.model_id))
+                .map(|m| m.instance_id)
+                .collect();
+            
+            if instance_ids.is_empty() {
+                return Err(CliError::ModelNotFound(format!(
+                    "no loaded instance of '{}' (see 'lmo status')", model_ref
+                )).into());
+            }
+            
+            output.header(&format!("Unloading Model: {} ({} instance(s))", model_ref, instance_ids.len()));
+            eprintln!();
+            instance_ids
+        }
+        (None, None) => unreachable!("clap requires an instance id or --model"),
+    };
     
+    for instance_id in &instance_ids {
+        if cmd.model.is_some() {
+            output.subheader(&format!("Instance {}", instance_id));
+        } else {
+            output.header(&format!("Unloading Model Instance: {}", instance_id));
+            eprintln!();
+        }
+        
+        // Unloading mid-generation disrupts other users, so ask first
+        if !(cmd.yes || cmd.force) && !confirm(&format!("Unload instance {}?", instance_id))? {
+            output.info("Unload cancelled");
+            continue;
+        }
+        
+        unload_instance(&client, &cmd, instance_id, &output).await;
+    }
+    
+    Ok(())
+}
+
+/// Unload a single instance and report the result
+async fn unload_instance(client: &LmoClient, cmd: &UnloadCommand, instance_id: &str, output: &OutputFormatter) {
     // Send unload request
     output.progress("Sending unload request to server");
     
     let unload_request = lmoclient::models::UnloadModelRequest {
-        instance_id: cmd.instance_id.clone(),
+        instance_id: instance_id.to_string(),
     };
     
-    let result = client.unload_model(unload_request).await;
+    let result = timed(output, "unload_model", client.unload_model(unload_request)).await;
     output.progress_done();
     
     match result {
@@ -39,7 +85,7 @@ pub async fn handle(cmd: UnloadCommand, config: &CliConfig) -> Result<()> {
                 output.key_value("Duration", &format!("{}ms", response.duration_ms));
             } else {
                 output.warning(&format!("Model unload failed: {}", response.message));
-                output.key_value("Instance ID", &cmd.instance_id);
+                output.key_value("Instance ID", instance_id);
                 
                 if cmd.force {
                     output.info("Force unload was requested but failed");
@@ -49,15 +95,13 @@ pub async fn handle(cmd: UnloadCommand, config: &CliConfig) -> Result<()> {
         Err(e) => {
             output.warning(&format!("Failed to communicate with server: {}", e));
             
-            println!();
+            eprintln!();
             output.subheader("Attempted Unload Operation");
-            output.key_value("Instance ID", &cmd.instance_id);
+            output.detail("Instance ID", instance_id);
             
             if cmd.force {
-                output.key_value("Force Unload", "Yes");
+                output.detail("Force Unload", "Yes");
             }
         }
     }
-    
-    Ok(())
 }
\ No newline at end of file
@@ -2,9 +2,14 @@
  * CLI Configuration Management
  *
  * Handles loading, saving, and managing CLI configuration.
+ *
+ * Settings come from `~/.config/lmo/config.toml` (or `--config <path>`); any
+ * key missing from the file keeps its built-in default, and command-line flags
+ * override both.
  */
 
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use dirs::config_dir;
@@ -12,6 +17,7 @@ use dirs::config_dir;
 use crate::error::CliError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CliConfig {
     /// Default server URL
     pub server_url: String,
@@ -22,14 +28,54 @@ pub struct CliConfig {
     /// Enable colors by default
     pub enable_colors: bool,
     
+    /// Model used by chat when none is given
+    pub default_model: Option<String>,
+    
+    /// Request timeout in seconds; 30 when unset, 0 disables it
+    pub timeout_secs: Option<u64>,
+    
+    /// Token sent to the server for authentication
+    pub auth_token: Option<String>,
+    
+    /// Retries for idempotent requests on connection errors
+    pub retries: u32,
+    
     /// Default chat settings
     pub chat: ChatConfig,
     
     /// Default model settings
     pub models: ModelsConfig,
+    
+    /// Default download settings
+    #[serde(default)]
+    pub download: DownloadConfig,
+    
+    /// Opt-in failure reporting
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+    
+    /// Named server profiles, selected with --profile
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    
+    /// Profile applied on top of the file settings, if any (never saved)
+    #[serde(skip)]
+    pub active_profile: Option<String>,
+    
+    /// Suppress progress, info and success chatter (set by --quiet, never saved)
+    #[serde(skip)]
+    pub quiet: bool,
+    
+    /// Number of -v flags given; debug messages need at least one (never saved)
+    #[serde(skip)]
+    pub verbosity: u8,
+    
+    /// File this configuration was loaded from and is saved to (--config)
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ChatConfig {
     /// Default temperature
     pub temperature: f32,
@@ -48,9 +94,27 @@ pub struct ChatConfig {
     
     /// Conversation history directory
     pub history_dir: Option<String>,
+    
+    /// Largest conversation file that will be loaded, in bytes
+    pub max_history_bytes: u64,
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            temperature: 0.7,
+            max_tokens: 1000,
+            stream: true,
+            system_prompt: None,
+            auto_save: false,
+            history_dir: None,
+            max_history_bytes: 10 * 1024 * 1024,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModelsConfig {
     /// Default search limit
     pub default_limit: u32,
@@ -63,6 +127,48 @@ pub struct ModelsConfig {
     
     /// Preferred model providers
     pub preferred_providers: Vec<String>,
+    
+    /// How long the cached remote model list stays fresh, in seconds
+    pub cache_ttl_secs: u64,
+}
+
+impl Default for ModelsConfig {
+    fn default() -> Self {
+        Self {
+            default_limit: 20,
+            default_sort: "downloads".to_string(),
+            default_direction: "desc".to_string(),
+            preferred_providers: vec![
+                "microsoft".to_string(),
+                "meta-llama".to_string(),
+                "huggingface".to_string(),
+            ],
+            cache_ttl_secs: 60 * 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DownloadConfig {
+    /// Mirror base URL to download from instead of the default hub
+    pub mirror: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    /// Endpoint that receives anonymized failure reports (disabled when unset)
+    pub report_errors: Option<String>,
+}
+
+/// A `[profiles.<name>]` section; each set value overrides the top-level setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileConfig {
+    pub server_url: Option<String>,
+    pub output_format: Option<String>,
+    pub auth_token: Option<String>,
 }
 
 impl Default for CliConfig {
@@ -71,47 +177,98 @@ impl Default for CliConfig {
             server_url: "http://localhost:3000".to_string(),
             output_format: "table".to_string(),
             enable_colors: true,
-            chat: ChatConfig {
-                temperature: 0.7,
-                max_tokens: 1000,
-                stream: true,
-                system_prompt: None,
-                auto_save: false,
-                history_dir: None,
-            },
-            models: ModelsConfig {
-                default_limit: 20,
-                default_sort: "downloads".to_string(),
-                default_direction: "desc".to_string(),
-                preferred_providers: vec![
-                    "microsoft".to_string(),
-                    "meta-llama".to_string(),
-                    "huggingface".to_string(),
-                ],
-            },
+            default_model: None,
+            timeout_secs: None,
+            auth_token: None,
+            retries: 2,
+            chat: ChatConfig::default(),
+            models: ModelsConfig::default(),
+            download: DownloadConfig::default(),
+            telemetry: TelemetryConfig::default(),
+            profiles: BTreeMap::new(),
+            active_profile: None,
+            quiet: false,
+            verbosity: 0,
+            source: None,
         }
     }
 }
 
 impl CliConfig {
-    /// Load configuration from file or create default
-    pub fn load() -> Result<Self> {
-        let config_path = Self::config_file_path()?;
+    /// Load configuration from `path` (or the default location). A missing default
+    /// file means the built-in defaults; a missing explicit `path` is an error.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let config_path = match path {
+            Some(path) => path.to_path_buf(),
+            None => Self::config_file_path()?,
+        };
         
-        if config_path.exists() {
+        let mut config = if config_path.exists() {
             let content = std::fs::read_to_string(&config_path)
                 .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
             
-            toml::from_str(&content)
-                .with_context(|| "Failed to parse config file")
+            toml::from_str(&content).map_err(|e| CliError::ConfigError(format!(
+                "Failed to parse config file {}: {}", config_path.display(), e
+            )))?
+        } else if path.is_some() {
+            return Err(CliError::ConfigError(format!(
+                "Config file not found: {} (create it with 'lmo config init')", config_path.display()
+            )).into());
         } else {
-            Ok(Self::default())
+            Self::default()
+        };
+        
+        config.source = path.map(Path::to_path_buf);
+        Ok(config)
+    }
+
+    /// Copy of the configuration with auth tokens masked, safe to display
+    pub fn redacted(&self) -> Self {
+        const MASK: &str = "********";
+        
+        let mut config = self.clone();
+        if config.auth_token.is_some() {
+            config.auth_token = Some(MASK.to_string());
+        }
+        for profile in config.profiles.values_mut() {
+            if profile.auth_token.is_some() {
+                profile.auth_token = Some(MASK.to_string());
+            }
         }
+        config
     }
 
-    /// Save configuration to file
+    /// Merge the named profile over the top-level settings for this run
+    pub fn apply_profile(&mut self, name: &str) -> Result<()> {
+        let profile = self.profiles.get(name).cloned().ok_or_else(|| {
+            let available: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            CliError::ConfigError(format!(
+                "Unknown profile '{}' (available: {})",
+                name,
+                if available.is_empty() { "none".to_string() } else { available.join(", ") }
+            ))
+        })?;
+        
+        if let Some(server_url) = profile.server_url {
+            self.server_url = server_url;
+        }
+        if let Some(output_format) = profile.output_format {
+            self.output_format = output_format;
+        }
+        if let Some(auth_token) = profile.auth_token {
+            self.auth_token = Some(auth_token);
+        }
+        
+        self.active_profile = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Save configuration to the file it was loaded from
     pub fn save(&self) -> Result<()> {
-        let config_path = Self::config_file_path()?;
+        let config_path = match self.source {
+            Some(ref path) => path.clone(),
+            None => Self::config_file_path()?,
+        };
         
         // Create config directory if it doesn't exist
         if let Some(parent) = config_path.parent() {
@@ -148,6 +305,23 @@ impl CliConfig {
             "output_format" => self.output_format = value.to_string(),
             "enable_colors" => self.enable_colors = value.parse()
                 .with_context(|| "Invalid boolean value for enable_colors")?,
+            "default_model" => self.default_model = if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string())
+            },
+            "auth_token" => self.auth_token = if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string())
+            },
+            "retries" => self.retries = value.parse()
+                .with_context(|| "Invalid integer value for retries")?,
+            "timeout_secs" => self.timeout_secs = if value.is_empty() {
+                None
+            } else {
+                Some(value.parse().with_context(|| "Invalid integer value for timeout_secs")?)
+            },
             "chat.temperature" => self.chat.temperature = value.parse()
                 .with_context(|| "Invalid float value for chat.temperature")?,
             "chat.max_tokens" => self.chat.max_tokens = value.parse()
@@ -166,10 +340,26 @@ impl CliConfig {
             } else { 
                 Some(value.to_string()) 
             },
+            "chat.max_history_bytes" => self.chat.max_history_bytes = value.parse()
+                .with_context(|| "Invalid integer value for chat.max_history_bytes")?,
             "models.default_limit" => self.models.default_limit = value.parse()
                 .with_context(|| "Invalid integer value for models.default_limit")?,
             "models.default_sort" => self.models.default_sort = value.to_string(),
+            "models.cache_ttl_secs" => self.models.cache_ttl_secs = value.parse()
+                .with_context(|| "Invalid integer value for models.cache_ttl_secs")?,
             "models.default_direction" => self.models.default_direction = value.to_string(),
+            "download.mirror" => self.download.mirror = if value.is_empty() {
+                None
+            } else {
+                crate::utils::validate_server_url(value)?;
+                Some(value.to_string())
+            },
+            "telemetry.report_errors" => self.telemetry.report_errors = if value.is_empty() {
+                None
+            } else {
+                crate::utils::validate_server_url(value)?;
+                Some(value.to_string())
+            },
             _ => return Err(CliError::ConfigError(format!("Unknown config key: {}", key)).into()),
         }
         Ok(())
@@ -181,15 +371,23 @@ impl CliConfig {
             "server_url" => self.server_url.clone(),
             "output_format" => self.output_format.clone(),
             "enable_colors" => self.enable_colors.to_string(),
+            "default_model" => self.default_model.as_deref().unwrap_or("").to_string(),
+            "timeout_secs" => self.timeout_secs.map(|secs| secs.to_string()).unwrap_or_default(),
+            "auth_token" => self.auth_token.as_deref().unwrap_or("").to_string(),
+            "retries" => self.retries.to_string(),
             "chat.temperature" => self.chat.temperature.to_string(),
             "chat.max_tokens" => self.chat.max_tokens.to_string(),
             "chat.stream" => self.chat.stream.to_string(),
             "chat.system_prompt" => self.chat.system_prompt.as_deref().unwrap_or("").to_string(),
             "chat.auto_save" => self.chat.auto_save.to_string(),
             "chat.history_dir" => self.chat.history_dir.as_deref().unwrap_or("").to_string(),
+            "chat.max_history_bytes" => self.chat.max_history_bytes.to_string(),
             "models.default_limit" => self.models.default_limit.to_string(),
             "models.default_sort" => self.models.default_sort.clone(),
             "models.default_direction" => self.models.default_direction.clone(),
+            "models.cache_ttl_secs" => self.models.cache_ttl_secs.to_string(),
+            "download.mirror" => self.download.mirror.as_deref().unwrap_or("").to_string(),
+            "telemetry.report_errors" => self.telemetry.report_errors.as_deref().unwrap_or("").to_string(),
             _ => return Err(CliError::ConfigError(format!("Unknown config key: {}", key)).into()),
         };
         Ok(value)
@@ -201,15 +399,23 @@ impl CliConfig {
             "server_url",
             "output_format",
             "enable_colors",
+            "default_model",
+            "timeout_secs",
+            "auth_token",
+            "retries",
             "chat.temperature",
             "chat.max_tokens",
             "chat.stream",
             "chat.system_prompt",
             "chat.auto_save",
             "chat.history_dir",
+            "chat.max_history_bytes",
             "models.default_limit",
             "models.default_sort",
             "models.default_direction",
+            "models.cache_ttl_secs",
+            "download.mirror",
+            "telemetry.report_errors",
         ]
     }
 }
\ No newline at end of file
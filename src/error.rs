@@ -31,6 +31,43 @@ pub enum CliError {
 
     #[error("Chat session error: {0}")]
     ChatError(String),
+
+    #[error("No models are currently loaded")]
+    NoModelsLoaded,
+
+    #[error("Server is not healthy: {0}")]
+    ServerUnhealthy(String),
+}
+
+impl CliError {
+    /// Process exit code for this error
+    ///
+    /// - `1`: general failure
+    /// - `3`: no model instances are loaded (`status --require-loaded`)
+    /// - `4`: the server reported an unhealthy status (`health`; degraded only with `--strict`)
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::NoModelsLoaded => 3,
+            CliError::ServerUnhealthy(_) => 4,
+            _ => 1,
+        }
+    }
+
+    /// Stable, content-free category name used in failure reports
+    pub fn category(&self) -> &'static str {
+        match self {
+            CliError::ConfigError(_) => "config",
+            CliError::ServerError(_) => "server",
+            CliError::ModelNotFound(_) => "model_not_found",
+            CliError::CommandError(_) => "command",
+            CliError::IoError(_) => "io",
+            CliError::InvalidInput(_) => "invalid_input",
+            CliError::AuthError(_) => "auth",
+            CliError::ChatError(_) => "chat",
+            CliError::NoModelsLoaded => "no_models_loaded",
+            CliError::ServerUnhealthy(_) => "server_unhealthy",
+        }
+    }
 }
 
 impl From<lmoclient::ClientError> for CliError {
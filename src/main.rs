@@ -8,46 +8,145 @@ mod cli;
 mod commands;
 mod config;
 mod error;
+mod model_cache;
+mod model_ref;
 mod output;
+mod telemetry;
 mod utils;
 
 use anyhow::Result;
 use clap::Parser;
-use tracing::{info, Level};
+use tracing::{debug, Level};
 use tracing_subscriber::{fmt, EnvFilter};
 
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, ConfigAction, ConfigCommand};
 use config::CliConfig;
+use error::CliError;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize logging
-    let filter = EnvFilter::builder()
-        .with_default_directive(Level::INFO.into())
-        .from_env_lossy();
+    // Parse command line arguments
+    let cli = Cli::parse();
+
+    // Initialize logging: --log-level wins, then RUST_LOG, then the -v count
+    let filter = match cli.log_level {
+        Some(level) => EnvFilter::new(level.as_str()),
+        None => {
+            let default_level = match cli.verbose {
+                0 => Level::WARN,
+                1 => Level::INFO,
+                2 => Level::DEBUG,
+                _ => Level::TRACE,
+            };
+            EnvFilter::builder()
+                .with_default_directive(default_level.into())
+                .from_env_lossy()
+        }
+    };
 
+    // Log to stderr so stdout only carries command output
     fmt()
         .with_env_filter(filter)
         .with_target(false)
+        .with_writer(std::io::stderr)
         .init();
 
-    // Parse command line arguments
-    let cli = Cli::parse();
-
     // Load configuration
-    let config = CliConfig::load().unwrap_or_default();
+    let mut config = match CliConfig::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        // init and reset write a fresh file, so a missing or broken one is expected
+        Err(e) if matches!(cli.command, Commands::Config(ConfigCommand { action: ConfigAction::Init | ConfigAction::Reset })) => {
+            debug!("{:#}; starting from the default configuration", e);
+            CliConfig { source: cli.config.clone(), ..CliConfig::default() }
+        }
+        Err(e) => {
+            let exit_code = e.downcast_ref::<CliError>()
+                .map(CliError::exit_code)
+                .unwrap_or(1);
+            eprintln!("Error: {:?}", e);
+            std::process::exit(exit_code);
+        }
+    };
+    
+    // A profile overrides the file's top-level settings; flags override both
+    if let Some(ref name) = cli.profile {
+        if let Err(e) = config.apply_profile(name) {
+            eprintln!("Error: {:?}", e);
+            std::process::exit(1);
+        }
+    }
+    
+    // Command-line flags take precedence over the config file
+    if let Some(ref url) = cli.server_url {
+        config.server_url = url.clone();
+    }
+
+    if let Some(ref format) = cli.output {
+        config.output_format = format.to_lowercase();
+    }
+
+    if let Some(timeout) = cli.timeout {
+        config.timeout_secs = Some(timeout);
+    }
+
+    if let Some(retries) = cli.retries {
+        config.retries = retries;
+    }
+
+    if let Some(ref api_key) = cli.api_key {
+        config.auth_token = Some(api_key.clone());
+    }
+
+    if cli.no_color {
+        config.enable_colors = false;
+    }
+
+    config.quiet = cli.quiet;
+    config.verbosity = cli.verbose;
+
+    if let Some(ref url) = cli.report_errors {
+        config.telemetry.report_errors = Some(url.clone());
+    }
+
+    debug!("LMO CLI starting");
 
-    info!("LMO CLI starting");
+    let command_name = cli.command.name();
 
     // Handle commands
-    match cli.command {
+    let result = match cli.command {
         Commands::Models(cmd) => commands::models::handle(cmd, &config).await,
-        Commands::Chat(cmd) => commands::chat::handle(cmd, &config, cli.verbose).await,
+        Commands::Chat(cmd) => commands::chat::handle(cmd, &config).await,
         Commands::Load(cmd) => commands::load::handle(cmd, &config).await,
         Commands::Unload(cmd) => commands::unload::handle(cmd, &config).await,
         Commands::Status(cmd) => commands::status::handle(cmd, &config).await,
         Commands::Config(cmd) => commands::config::handle(cmd, &config).await,
         Commands::Health(cmd) => commands::health::handle(cmd, &config).await,
         Commands::Download(cmd) => commands::download::handle(cmd, &config).await,
+        Commands::Scan(cmd) => commands::scan::handle(cmd, &config).await,
+        Commands::Import(cmd) => commands::import::handle(cmd, &config).await,
+        Commands::History(cmd) => commands::history::handle(cmd, &config).await,
+        Commands::Resolve(cmd) => commands::resolve::handle(cmd, &config).await,
+        Commands::Info(cmd) => commands::info::handle(cmd, &config).await,
+        Commands::Completions(cmd) => commands::completions::handle(cmd, &config).await,
+        Commands::Embeddings(cmd) => commands::embeddings::handle(cmd, &config).await,
+        Commands::Tokenize(cmd) => commands::tokenize::handle(cmd, &config).await,
+        Commands::Complete(cmd) => commands::complete::handle(cmd, &config).await,
+        Commands::Dashboard(cmd) => commands::dashboard::handle(cmd, &config).await,
+    };
+    
+    // Map CLI errors to their specific exit codes
+    if let Err(err) = result {
+        let exit_code = err.downcast_ref::<CliError>()
+            .map(CliError::exit_code)
+            .unwrap_or(1);
+        eprintln!("Error: {:?}", err);
+        
+        if let Some(ref url) = config.telemetry.report_errors {
+            telemetry::report_failure(url, cli.report_errors_dry_run, command_name, &err, &config).await;
+        }
+        
+        std::process::exit(exit_code);
     }
+    
+    Ok(())
 }
@@ -0,0 +1,128 @@
+/*!
+ * Remote Model List Cache
+ *
+ * Caches the server's remote model list on disk so repeated `models` and
+ * `load` calls don't refetch it from the hub every time.
+ */
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use dirs::cache_dir;
+use lmoclient::LmoClient;
+use lmoclient::models::ModelListResponse;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::config::CliConfig;
+use crate::utils::retry_idempotent;
+
+/// How the cache should be used for a fetch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Serve a fresh cache entry, otherwise fetch and store
+    Use,
+    /// Always fetch and never touch the cache
+    Bypass,
+    /// Always fetch and overwrite the cache
+    Refresh,
+}
+
+impl CachePolicy {
+    pub fn from_flags(no_cache: bool, refresh_cache: bool) -> Self {
+        match (no_cache, refresh_cache) {
+            (true, _) => CachePolicy::Bypass,
+            (false, true) => CachePolicy::Refresh,
+            (false, false) => CachePolicy::Use,
+        }
+    }
+}
+
+/// On-disk cache entry
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedModelList {
+    /// Server the list was fetched from
+    server_url: String,
+
+    /// Fetch time in seconds since the Unix epoch
+    fetched_at: u64,
+
+    response: ModelListResponse,
+}
+
+/// List remote models, going through the on-disk cache according to `policy`
+pub async fn list_models_cached(
+    client: &LmoClient,
+    config: &CliConfig,
+    policy: CachePolicy,
+) -> Result<ModelListResponse, lmoclient::ClientError> {
+    let server_url = client.config().server_url.clone();
+
+    if policy == CachePolicy::Use {
+        if let Some(response) = read_cache(&server_url, config.models.cache_ttl_secs) {
+            return Ok(response);
+        }
+    }
+
+    let response = retry_idempotent(config.retries, || client.list_models()).await?;
+
+    if policy != CachePolicy::Bypass {
+        let entry = CachedModelList { server_url, fetched_at: now_secs(), response };
+        write_cache(&entry);
+        return Ok(entry.response);
+    }
+
+    Ok(response)
+}
+
+/// Read the cached list if it belongs to this server and is younger than the TTL
+fn read_cache(server_url: &str, ttl_secs: u64) -> Option<ModelListResponse> {
+    let path = cache_file_path()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+
+    let entry: CachedModelList = match serde_json::from_str(&content) {
+        Ok(entry) => entry,
+        Err(e) => {
+            debug!("Ignoring unreadable model cache {}: {}", path.display(), e);
+            return None;
+        }
+    };
+
+    let age = now_secs().saturating_sub(entry.fetched_at);
+    if entry.server_url != server_url || age > ttl_secs {
+        debug!("Model cache is stale or for another server (age {}s)", age);
+        return None;
+    }
+
+    debug!("Using cached model list ({}s old)", age);
+    Some(entry.response)
+}
+
+/// Store the list; failures only cost a refetch next time, so they are just logged
+fn write_cache(entry: &CachedModelList) {
+    let Some(path) = cache_file_path() else {
+        return;
+    };
+
+    let result = path.parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| {
+            let json = serde_json::to_string(entry)?;
+            std::fs::write(&path, json)
+        });
+
+    if let Err(e) = result {
+        debug!("Failed to write model cache {}: {}", path.display(), e);
+    }
+}
+
+fn cache_file_path() -> Option<PathBuf> {
+    Some(cache_dir()?.join("lmo").join("models.json"))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
\ No newline at end of file
@@ -0,0 +1,225 @@
+/*!
+ * Model References
+ *
+ * Parsing, display, and matching of model identifiers such as
+ * `org/repo`, `org/repo/file.gguf`, or `org/repo:Q4_K_M`.
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::CliError;
+
+/// A user-supplied reference to a model
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModelRef {
+    /// Organization or author (e.g. "microsoft")
+    pub org: Option<String>,
+
+    /// Repository name (e.g. "DialoGPT-small")
+    pub repo: String,
+
+    /// Specific file within the repository
+    pub filename: Option<String>,
+
+    /// Quantization tag (e.g. "Q4_K_M")
+    pub quant: Option<String>,
+}
+
+impl ModelRef {
+    /// Parse a model reference, tolerating stray and repeated slashes
+    pub fn parse(input: &str) -> Result<Self, CliError> {
+        let input = input.trim();
+
+        // A trailing `:tag` selects a quantization, as long as it isn't part of a path
+        let (path, quant) = match input.rsplit_once(':') {
+            Some((path, tag)) if !tag.is_empty() && !tag.contains('/') => (path, Some(tag.to_string())),
+            Some((path, "")) => (path, None),
+            _ => (input, None),
+        };
+
+        let segments: Vec<&str> = path.split('/')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let (org, repo, filename) = match segments.as_slice() {
+            [] => return Err(CliError::InvalidInput(format!("Invalid model reference: '{}'", input))),
+            [repo] => (None, repo.to_string(), None),
+            [org, repo] => (Some(org.to_string()), repo.to_string(), None),
+            [org, repo, rest @ ..] => (Some(org.to_string()), repo.to_string(), Some(rest.join("/"))),
+        };
+
+        Ok(Self { org, repo, filename, quant })
+    }
+
+    /// The repository id without filename or quantization (e.g. "org/repo")
+    pub fn repo_id(&self) -> String {
+        match self.org {
+            Some(ref org) => format!("{}/{}", org, self.repo),
+            None => self.repo.clone(),
+        }
+    }
+
+    /// Whether `id` names exactly this model (case-insensitive)
+    pub fn matches_exact(&self, id: &str) -> bool {
+        let id = id.trim_matches('/').to_lowercase();
+        let repo_id = self.repo_id().to_lowercase();
+
+        match self.filename {
+            Some(ref filename) => id == format!("{}/{}", repo_id, filename.to_lowercase()),
+            None => id == repo_id,
+        }
+    }
+
+    /// Whether `id` is this model or a file within it (case-insensitive)
+    pub fn matches_prefix(&self, id: &str) -> bool {
+        let id = id.trim_matches('/').to_lowercase();
+        let repo_id = self.repo_id().to_lowercase();
+
+        let in_repo = id == repo_id || id.starts_with(&format!("{}/", repo_id));
+        let in_file = match self.filename {
+            Some(ref filename) => id.contains(&filename.to_lowercase()),
+            None => true,
+        };
+        let has_quant = match self.quant {
+            Some(ref quant) => id.contains(&quant.to_lowercase()),
+            None => true,
+        };
+
+        in_repo && in_file && has_quant
+    }
+
+    /// Whether `id` loosely resembles this model (case-insensitive substring match)
+    pub fn matches_fuzzy(&self, id: &str) -> bool {
+        let id = id.to_lowercase();
+
+        let repo_matches = match self.org {
+            Some(_) => id.contains(&self.repo_id().to_lowercase()),
+            None => id.contains(&self.repo.to_lowercase()),
+        };
+        let has_quant = match self.quant {
+            Some(ref quant) => id.contains(&quant.to_lowercase()),
+            None => true,
+        };
+
+        repo_matches && has_quant
+    }
+}
+
+/// Extract a quantization tag (e.g. "Q4_K_M", "IQ3_XS", "F16") from a model filename
+pub fn quantization_from_filename(filename: &str) -> Option<String> {
+    let name = filename.rsplit('/').next().unwrap_or(filename).to_uppercase();
+
+    name.split(['.', '-'])
+        .find(|segment| {
+            let is_k_quant = segment.strip_prefix("IQ")
+                .or_else(|| segment.strip_prefix('Q'))
+                .and_then(|rest| rest.chars().next())
+                .is_some_and(|c| c.is_ascii_digit());
+            let is_float = matches!(*segment, "F16" | "F32" | "BF16" | "FP16" | "FP32");
+
+            is_k_quant || is_float
+        })
+        .map(str::to_string)
+}
+
+impl FromStr for ModelRef {
+    type Err = CliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for ModelRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.repo_id())?;
+
+        if let Some(ref filename) = self.filename {
+            write!(f, "/{}", filename)?;
+        }
+
+        if let Some(ref quant) = self.quant {
+            write!(f, ":{}", quant)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> ModelRef {
+        ModelRef::parse(input).unwrap()
+    }
+
+    #[test]
+    fn parses_bare_repo_without_slash() {
+        let model = parse("DialoGPT-small");
+        assert_eq!(model.org, None);
+        assert_eq!(model.repo, "DialoGPT-small");
+        assert_eq!(model.filename, None);
+        assert_eq!(model.quant, None);
+    }
+
+    #[test]
+    fn parses_org_and_repo() {
+        let model = parse("microsoft/DialoGPT-small");
+        assert_eq!(model.org.as_deref(), Some("microsoft"));
+        assert_eq!(model.repo, "DialoGPT-small");
+        assert_eq!(model.repo_id(), "microsoft/DialoGPT-small");
+    }
+
+    #[test]
+    fn joins_extra_segments_into_filename() {
+        let model = parse("org/repo/subdir/model.Q4_K_M.gguf");
+        assert_eq!(model.repo_id(), "org/repo");
+        assert_eq!(model.filename.as_deref(), Some("subdir/model.Q4_K_M.gguf"));
+    }
+
+    #[test]
+    fn ignores_stray_and_repeated_slashes() {
+        let model = parse("/org//repo/");
+        assert_eq!(model.repo_id(), "org/repo");
+        assert_eq!(model.filename, None);
+    }
+
+    #[test]
+    fn splits_quant_tag_suffix() {
+        let model = parse("org/repo:Q4_K_M");
+        assert_eq!(model.repo_id(), "org/repo");
+        assert_eq!(model.quant.as_deref(), Some("Q4_K_M"));
+    }
+
+    #[test]
+    fn ignores_empty_quant_tag() {
+        let model = parse("org/repo:");
+        assert_eq!(model.repo_id(), "org/repo");
+        assert_eq!(model.quant, None);
+    }
+
+    #[test]
+    fn keeps_colon_inside_path() {
+        let model = parse("org/repo:v2/model.gguf");
+        assert_eq!(model.quant, None);
+        assert_eq!(model.repo, "repo:v2");
+        assert_eq!(model.filename.as_deref(), Some("model.gguf"));
+    }
+
+    #[test]
+    fn rejects_empty_repo() {
+        for input in ["", "   ", "/", "//", ":Q4_K_M", "/:Q4_K_M"] {
+            assert!(ModelRef::parse(input).is_err(), "expected '{}' to be rejected", input);
+        }
+    }
+
+    #[test]
+    fn displays_in_parse_form() {
+        for input in ["repo", "org/repo", "org/repo/model.gguf", "org/repo:Q4_K_M"] {
+            assert_eq!(parse(input).to_string(), input);
+        }
+    }
+}
\ No newline at end of file
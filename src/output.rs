@@ -2,18 +2,30 @@
  * Output Formatting
  * 
  * Handles different output formats (table, JSON, YAML) and styling.
+ *
+ * Only the data payload (`print`, tables, key-value data) is written to stdout.
+ * Progress, status, info, warnings, errors and headers go to stderr so that
+ * output can be piped reliably.
+ *
+ * Colors and emoji are dropped in favour of plain ASCII when `--no-color` is
+ * passed, `NO_COLOR` is set, colors are disabled in the config, or stdout is
+ * not a terminal. With `--quiet`, progress, info, success and header output is
+ * suppressed; warnings, errors and data are always printed.
  */
 
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use anyhow::Result;
 use colored::*;
 use serde::Serialize;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::config::CliConfig;
 
 pub struct OutputFormatter {
     format: OutputFormat,
-    enable_colors: bool,
+    no_color: bool,
+    quiet: bool,
+    verbosity: u8,
 }
 
 #[derive(Debug, Clone)]
@@ -31,9 +43,14 @@ impl OutputFormatter {
             _ => OutputFormat::Table,
         };
 
+        // https://no-color.org: any non-empty value disables color
+        let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        
         Self {
             format,
-            enable_colors: config.enable_colors && !no_color,
+            no_color: no_color || no_color_env || !config.enable_colors || !io::stdout().is_terminal(),
+            quiet: config.quiet,
+            verbosity: config.verbosity,
         }
     }
 
@@ -60,57 +77,96 @@ impl OutputFormatter {
 
     /// Print a success message
     pub fn success(&self, message: &str) {
-        if self.enable_colors {
-            println!("{} {}", "✓".green().bold(), message);
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{} {}", "✓".green().bold(), message);
         } else {
-            println!("✓ {}", message);
+            eprintln!("[ok] {}", message);
         }
     }
 
     /// Print an error message
     pub fn error(&self, message: &str) {
-        if self.enable_colors {
+        if !self.no_color {
             eprintln!("{} {}", "✗".red().bold(), message.red());
         } else {
-            eprintln!("✗ {}", message);
+            eprintln!("[error] {}", message);
         }
     }
 
     /// Print a warning message
     pub fn warning(&self, message: &str) {
-        if self.enable_colors {
-            println!("{} {}", "⚠".yellow().bold(), message.yellow());
+        if !self.no_color {
+            eprintln!("{} {}", "⚠".yellow().bold(), message.yellow());
         } else {
-            println!("⚠ {}", message);
+            eprintln!("[warn] {}", message);
         }
     }
 
     /// Print an info message
     pub fn info(&self, message: &str) {
-        if self.enable_colors {
-            println!("{} {}", "ℹ".blue().bold(), message);
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{} {}", "ℹ".blue().bold(), message);
         } else {
-            println!("ℹ {}", message);
+            eprintln!("[info] {}", message);
         }
     }
 
     /// Print a header
     pub fn header(&self, text: &str) {
-        if self.enable_colors {
-            println!("{}", text.bold().underline());
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{}", text.bold().underline());
         } else {
-            println!("{}", text);
-            println!("{}", "=".repeat(text.len()));
+            eprintln!("{}", text);
+            eprintln!("{}", "=".repeat(text.len()));
         }
     }
 
     /// Print a subheader
     pub fn subheader(&self, text: &str) {
-        if self.enable_colors {
-            println!("{}", text.bold());
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{}", text.bold());
+        } else {
+            eprintln!("{}", text);
+            eprintln!("{}", "-".repeat(text.len()));
+        }
+    }
+
+    /// Print markdown with terminal styling, or verbatim when colors are disabled
+    pub fn markdown(&self, text: &str) {
+        if !self.no_color {
+            termimad::MadSkin::default().print_text(text);
         } else {
             println!("{}", text);
-            println!("{}", "-".repeat(text.len()));
+        }
+    }
+
+    /// Print a diff line: `-` lines are removed (red), `+` lines added (green)
+    pub fn diff_line(&self, sign: char, text: &str) {
+        let line = format!("{} {}", sign, text);
+        if !self.no_color {
+            match sign {
+                '-' => println!("{}", line.red()),
+                '+' => println!("{}", line.green()),
+                _ => println!("{}", line.dimmed()),
+            }
+        } else {
+            println!("{}", line);
         }
     }
 
@@ -118,65 +174,116 @@ impl OutputFormatter {
     pub fn table_row(&self, cells: &[&str]) -> String {
         cells.join(" | ")
     }
+    
+    /// Whether colors and emoji are disabled
+    pub fn no_color(&self) -> bool {
+        self.no_color
+    }
+    
+    /// Whether progress, info and success chatter is suppressed
+    pub fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+    
+    /// Number of -v flags given
+    pub fn verbosity(&self) -> u8 {
+        self.verbosity
+    }
 
     /// Format a key-value pair
     pub fn key_value(&self, key: &str, value: &str) {
-        if self.enable_colors {
+        if !self.no_color {
             println!("{}: {}", key.cyan().bold(), value);
         } else {
             println!("{}: {}", key, value);
         }
     }
 
+    /// Format a key-value pair that describes the operation rather than its result;
+    /// suppressed by --quiet like other informational output
+    pub fn detail(&self, key: &str, value: &str) {
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{}: {}", key.cyan().bold(), value);
+        } else {
+            eprintln!("{}: {}", key, value);
+        }
+    }
+
     /// Print a progress indicator
     pub fn progress(&self, message: &str) {
-        if self.enable_colors {
-            print!("{} {}... ", "○".blue(), message);
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprint!("{} {}... ", "○".blue(), message);
         } else {
-            print!("○ {}... ", message);
+            eprint!("- {}... ", message);
         }
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
 
     /// Print completion of progress
     pub fn progress_done(&self) {
-        if self.enable_colors {
-            println!("{}", "done".green());
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{}", "done".green());
         } else {
-            println!("done");
+            eprintln!("done");
         }
     }
 
-    /// Print failure of progress
+    /// Print failure of progress; in quiet mode the progress line was never
+    /// shown, so this becomes a standalone error
     pub fn progress_failed(&self, error: &str) {
-        if self.enable_colors {
-            println!("{}: {}", "failed".red(), error.red());
+        if self.quiet {
+            self.error(error);
+            return;
+        }
+        if !self.no_color {
+            eprintln!("{}: {}", "failed".red(), error.red());
         } else {
-            println!("failed: {}", error);
+            eprintln!("failed: {}", error);
         }
     }
     
     /// Print a status message
     pub fn status(&self, message: &str) {
-        if self.enable_colors {
-            print!("{} {}... ", "⚡".yellow(), message);
+        if self.quiet {
+            return;
+        }
+        
+        if !self.no_color {
+            eprint!("{} {}... ", "⚡".yellow(), message);
         } else {
-            print!("⚡ {}... ", message);
+            eprint!("* {}... ", message);
         }
-        io::stdout().flush().unwrap();
+        io::stderr().flush().unwrap();
     }
     
-    /// Print a debug message
+    /// Print a debug message; only shown with -v
     pub fn debug(&self, message: &str) {
-        if self.enable_colors {
-            println!("{} {}", "🔍".dimmed(), message.dimmed());
+        if self.quiet || self.verbosity == 0 {
+            return;
+        }
+        
+        if !self.no_color {
+            eprintln!("{} {}", "🔍".dimmed(), message.dimmed());
         } else {
-            println!("🔍 {}", message);
+            eprintln!("[debug] {}", message);
         }
     }
 }
 
-/// Helper to format file sizes
+/// Helper to format file sizes (1 KB = 1024 B); the single formatter for byte
+/// counts across all commands
 pub fn format_bytes(bytes: u64) -> String {
     const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
     let mut size = bytes as f64;
@@ -209,11 +316,107 @@ pub fn format_number(num: u64) -> String {
     result.chars().rev().collect()
 }
 
-/// Helper to truncate text with ellipsis
-pub fn truncate_text(text: &str, max_len: usize) -> String {
-    if text.len() <= max_len {
-        text.to_string()
-    } else {
-        format!("{}...", &text[..max_len.saturating_sub(3)])
+/// Helper to truncate text with ellipsis, measured in terminal columns so that
+/// wide characters (CJK, emoji) are accounted for
+pub fn truncate_text(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    
+    let target = max_width.saturating_sub(3);
+    let mut width = 0;
+    let mut truncated = String::new();
+    
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > target {
+            break;
+        }
+        width += char_width;
+        truncated.push(c);
+    }
+    
+    format!("{}...", truncated)
+}
+
+/// Helper to left-align text in a column of the given display width
+pub fn pad_text(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.width());
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Helper to truncate and pad text so it fills exactly one table column
+pub fn fit_text(text: &str, width: usize) -> String {
+    pad_text(&truncate_text(text, width), width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn format_bytes_prints_whole_bytes_below_one_kb() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1), "1 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+    }
+    
+    #[test]
+    fn format_bytes_scales_to_larger_units() {
+        assert_eq!(format_bytes(1024), "1.0 KB");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(format_bytes(4_370_000_000), "4.1 GB");
+    }
+    
+    #[test]
+    fn format_bytes_stops_at_terabytes() {
+        assert_eq!(format_bytes(1024_u64.pow(4)), "1.0 TB");
+        assert_eq!(format_bytes(2048 * 1024_u64.pow(4)), "2048.0 TB");
+    }
+    
+    #[test]
+    fn truncate_keeps_text_that_fits() {
+        assert_eq!(truncate_text("model", 5), "model");
+        assert_eq!(truncate_text("日本語", 6), "日本語");
+        assert_eq!(truncate_text("🚀🚀", 4), "🚀🚀");
+    }
+    
+    #[test]
+    fn truncate_counts_display_width_not_chars() {
+        // Three chars but six columns wide
+        assert_eq!(truncate_text("日本語", 5), "日...");
+        assert_eq!(truncate_text("日本語モデル", 9), "日本語...");
+        assert_eq!(truncate_text("🚀🚀🚀🚀", 7), "🚀🚀...");
+    }
+    
+    #[test]
+    fn truncate_never_splits_a_wide_char() {
+        // Eight columns minus the ellipsis leave five: two wide chars fit, the third would straddle the edge
+        let truncated = truncate_text("日本語テキスト", 8);
+        assert_eq!(truncated, "日本...");
+        assert!(truncated.width() <= 8);
+        
+        let truncated = truncate_text("a🚀🚀🚀", 5);
+        assert_eq!(truncated, "a...");
+        assert!(truncated.width() <= 5);
+    }
+    
+    #[test]
+    fn pad_fills_to_display_width() {
+        assert_eq!(pad_text("ab", 4), "ab  ");
+        assert_eq!(pad_text("日本", 6), "日本  ");
+        assert_eq!(pad_text("🚀", 3), "🚀 ");
+        assert_eq!(pad_text("日本語", 4), "日本語");
+    }
+    
+    #[test]
+    fn fit_produces_exact_column_width() {
+        for text in ["short", "a much longer model name", "日本語テキスト", "a🚀🚀🚀", "🚀"] {
+            for width in [5, 6, 8, 12] {
+                let fitted = fit_text(text, width);
+                assert_eq!(fitted.width(), width, "fit_text({:?}, {}) = {:?}", text, width, fitted);
+            }
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,69 @@
+/*!
+ * Failure Reporting
+ *
+ * Opt-in, anonymized failure reports for self-hosted dashboards. Reports only
+ * carry the command name, an error category, and version numbers - never
+ * prompts, model data, server URLs, or error messages.
+ */
+
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing::debug;
+
+use crate::config::CliConfig;
+use crate::error::CliError;
+use crate::utils::create_client;
+
+/// How long to wait on the server version lookup and the report itself
+const REPORT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Anonymized failure report payload
+#[derive(Debug, Serialize)]
+pub struct FailureReport {
+    pub command: &'static str,
+    pub error_category: &'static str,
+    pub client_version: &'static str,
+    pub server_version: Option<String>,
+}
+
+/// Build and send (or, with `dry_run`, print) a failure report; never fails the CLI
+pub async fn report_failure(url: &str, dry_run: bool, command: &'static str, err: &anyhow::Error, config: &CliConfig) {
+    let report = FailureReport {
+        command,
+        error_category: err.downcast_ref::<CliError>()
+            .map(CliError::category)
+            .unwrap_or("other"),
+        client_version: env!("CARGO_PKG_VERSION"),
+        server_version: server_version(config).await,
+    };
+
+    if dry_run {
+        let payload = serde_json::to_string_pretty(&report).unwrap_or_default();
+        eprintln!("Failure report (dry run, not sent to {}):", url);
+        eprintln!("{}", payload);
+        return;
+    }
+
+    let result = reqwest::Client::new()
+        .post(url)
+        .timeout(REPORT_TIMEOUT)
+        .json(&report)
+        .send()
+        .await;
+
+    match result {
+        Ok(response) => debug!("Failure report sent to {} ({})", url, response.status()),
+        Err(e) => debug!("Failed to send failure report to {}: {}", url, e),
+    }
+}
+
+/// Best-effort server version lookup; `None` if the server is unreachable
+async fn server_version(config: &CliConfig) -> Option<String> {
+    let client = create_client(config, None).ok()?;
+
+    match tokio::time::timeout(REPORT_TIMEOUT, client.health()).await {
+        Ok(Ok(health)) => Some(health.server_version),
+        _ => None,
+    }
+}
\ No newline at end of file
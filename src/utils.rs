@@ -4,30 +4,67 @@
  * Helper functions and utilities for CLI operations.
  */
 
-use std::io::{self, Write};
+use std::future::Future;
+use std::io::{self, IsTerminal, Read, Write};
+use std::time::{Duration, Instant};
 use anyhow::Result;
-use dialoguer::{Select, Confirm, Input};
+use indicatif::{ProgressBar, ProgressStyle};
+use dialoguer::{Select, Confirm, FuzzySelect, Input};
 use lmoclient::{LmoClient, ClientConfig, ModelInfo};
+use lmoclient::models::LoadedModelInfo;
+use tracing::{debug, info};
 
 use crate::config::CliConfig;
 use crate::error::CliError;
 use crate::output::OutputFormatter;
 
+/// Request timeout used when the configuration doesn't set one
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Delay before the first retry; doubled on each further attempt
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
 /// Create an HTTP client from CLI configuration
 pub fn create_client(config: &CliConfig, server_url_override: Option<&str>) -> Result<LmoClient> {
     let server_url = config.server_url(server_url_override);
+    debug!("Creating client for {}", server_url);
     
-    let client_config = ClientConfig::new(server_url)?
+    let mut client_config = ClientConfig::new(server_url)?
         .with_logging(true);
     
+    match config.timeout_secs.unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS) {
+        0 => debug!("Request timeout disabled"),
+        secs => client_config = client_config.with_timeout(Duration::from_secs(secs)),
+    }
+    
+    // Sent as an `Authorization: Bearer` header; the token itself is never logged
+    if let Some(ref token) = config.auth_token {
+        debug!("Using bearer token authentication");
+        client_config = client_config.with_api_key(token.clone());
+    }
+    
     Ok(LmoClient::with_config(client_config)?)
 }
 
-/// Interactive model selection
-pub async fn select_model(client: &LmoClient, output: &OutputFormatter) -> Result<String> {
+/// Create a client for long-lived streams (chat replies, download progress):
+/// like `create_client`, but without a request timeout
+pub fn create_streaming_client(config: &CliConfig) -> Result<LmoClient> {
+    let config = CliConfig { timeout_secs: Some(0), ..config.clone() };
+    create_client(&config, None)
+}
+
+/// Interactive model selection; type to fuzzy-filter the list
+pub async fn select_model(client: &LmoClient, retries: u32, output: &OutputFormatter) -> Result<String> {
+    // A prompt would wait forever for input that can't arrive
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        return Err(CliError::InvalidInput(
+            "Interactive model selection needs a terminal; pass a model ID instead".to_string()
+        ).into());
+    }
+    
     output.progress("Fetching available models");
     
-    let models_response = client.list_models().await
+    let models_response = retry_idempotent(retries, || client.list_models()).await
         .map_err(|e| CliError::ServerError(format!("Failed to fetch models: {}", e)))?;
     
     output.progress_done();
@@ -41,8 +78,8 @@ pub async fn select_model(client: &LmoClient, output: &OutputFormatter) -> Resul
         .map(|m| format!("{} ({})", m.id, m.pipeline_tag.as_deref().unwrap_or("unknown")))
         .collect();
     
-    let selection = Select::new()
-        .with_prompt("Select a model")
+    let selection = FuzzySelect::new()
+        .with_prompt("Select a model (type to filter)")
         .items(&model_choices)
         .default(0)
         .interact()?;
@@ -51,10 +88,10 @@ pub async fn select_model(client: &LmoClient, output: &OutputFormatter) -> Resul
 }
 
 /// Interactive model selection for loaded models
-pub async fn select_loaded_model(client: &LmoClient, output: &OutputFormatter) -> Result<String> {
+pub async fn select_loaded_model(client: &LmoClient, retries: u32, output: &OutputFormatter) -> Result<String> {
     output.progress("Fetching loaded models");
     
-    let loaded_models = client.loaded_models().await
+    let loaded_models = fetch_loaded_models(client, retries).await
         .map_err(|e| CliError::ServerError(format!("Failed to fetch loaded models: {}", e)))?;
     
     output.progress_done();
@@ -77,6 +114,19 @@ pub async fn select_loaded_model(client: &LmoClient, output: &OutputFormatter) -
     Ok(loaded_models[selection].model_id.clone())
 }
 
+/// Fetch loaded model instances in a stable order: oldest load first, then by model ID
+pub async fn fetch_loaded_models(client: &LmoClient, retries: u32) -> Result<Vec<LoadedModelInfo>, lmoclient::ClientError> {
+    let mut loaded_models = retry_idempotent(retries, || client.loaded_models()).await?;
+    
+    loaded_models.sort_by(|a, b| {
+        a.loaded_at.cmp(&b.loaded_at)
+            .then_with(|| a.model_id.cmp(&b.model_id))
+            .then_with(|| a.instance_id.cmp(&b.instance_id))
+    });
+    
+    Ok(loaded_models)
+}
+
 /// Confirm action with user
 pub fn confirm_action(message: &str, default: bool) -> Result<bool> {
     Ok(Confirm::new()
@@ -85,6 +135,16 @@ pub fn confirm_action(message: &str, default: bool) -> Result<bool> {
         .interact()?)
 }
 
+/// Ask for confirmation of a destructive action, defaulting to no. Without a
+/// terminal to prompt on, proceeds so that scripts are never blocked.
+pub fn confirm(message: &str) -> Result<bool> {
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        return Ok(true);
+    }
+    
+    confirm_action(message, false)
+}
+
 /// Get user input
 pub fn get_input(prompt: &str, default: Option<&str>) -> Result<String> {
     let mut input_builder = Input::<String>::new().with_prompt(prompt);
@@ -96,6 +156,44 @@ pub fn get_input(prompt: &str, default: Option<&str>) -> Result<String> {
     Ok(input_builder.interact_text()?)
 }
 
+/// Read command input from the argument, a file, or piped stdin, in that order
+pub fn read_input_text(text: Option<&str>, file: Option<&str>) -> Result<String> {
+    if let Some(text) = text {
+        return Ok(text.to_string());
+    }
+    
+    if let Some(path) = file {
+        return std::fs::read_to_string(path)
+            .map_err(|e| CliError::IoError(format!("Failed to read {}: {}", path, e)).into());
+    }
+    
+    if io::stdin().is_terminal() {
+        return Err(CliError::InvalidInput(
+            "No input: pass text as an argument, use --file, or pipe it on stdin".to_string()
+        ).into());
+    }
+    
+    let mut piped = String::new();
+    io::stdin().read_to_string(&mut piped)
+        .map_err(|e| CliError::IoError(format!("Failed to read stdin: {}", e)))?;
+    Ok(piped)
+}
+
+/// Read text from the system clipboard
+pub fn read_clipboard() -> Result<String> {
+    let mut clipboard = arboard::Clipboard::new()
+        .map_err(|e| CliError::InvalidInput(format!("No clipboard available: {}", e)))?;
+    
+    let text = clipboard.get_text()
+        .map_err(|e| CliError::InvalidInput(format!("Clipboard does not contain text: {}", e)))?;
+    
+    if text.trim().is_empty() {
+        return Err(CliError::InvalidInput("Clipboard is empty".to_string()).into());
+    }
+    
+    Ok(text)
+}
+
 /// Validate server URL format
 pub fn validate_server_url(url: &str) -> Result<()> {
     url::Url::parse(url)
@@ -134,10 +232,10 @@ pub fn format_model_info(model: &ModelInfo) -> String {
 }
 
 /// Check if the server is accessible
-pub async fn check_server_health(client: &LmoClient, output: &OutputFormatter) -> Result<()> {
+pub async fn check_server_health(client: &LmoClient, retries: u32, output: &OutputFormatter) -> Result<()> {
     output.progress("Checking server health");
     
-    match client.health().await {
+    match timed(output, "health", retry_idempotent(retries, || client.health())).await {
         Ok(health) => {
             output.progress_done();
             output.success(&format!("Server is healthy ({})", health.status));
@@ -150,6 +248,99 @@ pub async fn check_server_health(client: &LmoClient, output: &OutputFormatter) -
     }
 }
 
+/// Ticking spinner on stderr, hidden in quiet mode or when stderr isn't a terminal
+pub fn spinner(output: &OutputFormatter, template: &str) -> ProgressBar {
+    let spinner = if output.is_quiet() || !io::stderr().is_terminal() {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new_spinner()
+    };
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template(template)
+            .expect("Invalid spinner template")
+    );
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    spinner
+}
+
+/// Await a client call, printing how long it took under -vv; a plain await otherwise
+pub async fn timed<F: Future>(output: &OutputFormatter, label: &str, request: F) -> F::Output {
+    if output.verbosity() < 2 {
+        return request.await;
+    }
+    
+    let started = Instant::now();
+    let result = request.await;
+    output.debug(&format!("{} took {}ms", label, started.elapsed().as_millis()));
+    result
+}
+
+/// Run an idempotent request (health, model lists), retrying transient errors up to
+/// `retries` times (`config.retries`) with exponential backoff. Requests with side
+/// effects must not go through here.
+pub async fn retry_idempotent<T, F, Fut>(retries: u32, mut request: F) -> Result<T, lmoclient::ClientError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, lmoclient::ClientError>>,
+{
+    let mut attempt = 0;
+    
+    loop {
+        match request().await {
+            Err(e) if attempt < retries && is_transient_error(&e) => {
+                attempt += 1;
+                // Shown with -v so a slow command explains itself
+                info!("{}; retrying ({}/{})...", e, attempt, retries);
+                tokio::time::sleep(RETRY_BASE_DELAY * 2u32.pow(attempt.min(6) - 1)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Retry a request that isn't idempotent (e.g. a chat completion) up to `attempts`
+/// times in total, but only on transient errors; everything else fails fast
+pub async fn retry_transient<T, F, Fut>(attempts: u32, output: &OutputFormatter, mut request: F) -> Result<T, lmoclient::ClientError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, lmoclient::ClientError>>,
+{
+    let mut attempt = 1;
+    
+    loop {
+        match request().await {
+            Err(e) if attempt < attempts && is_transient_error(&e) => {
+                output.debug(&format!("{}; retrying ({}/{})...", e, attempt, attempts - 1));
+                tokio::time::sleep(RETRY_BASE_DELAY * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Whether a client error is likely transient (connection dropped, timeout,
+/// 5xx from an overloaded or restarting server) and worth retrying
+pub fn is_transient_error(error: &lmoclient::ClientError) -> bool {
+    match error {
+        lmoclient::ClientError::ApiError { status, .. } => is_transient_status(*status),
+        // Dropped or refused connections and timeouts; builder and decode errors won't change on retry
+        lmoclient::ClientError::RequestError(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.is_request()
+                || e.status().is_some_and(|status| is_transient_status(status.as_u16()))
+        }
+        _ => false,
+    }
+}
+
+/// 5xx statuses an overloaded or restarting server answers with; 501 and the like won't change on retry
+fn is_transient_status(status: u16) -> bool {
+    matches!(status, 500 | 502 | 503 | 504)
+}
+
 /// Wait for user input to continue
 pub fn wait_for_enter(message: &str) {
     print!("{} Press Enter to continue...", message);
@@ -171,4 +362,33 @@ pub fn format_duration(seconds: u64) -> String {
     } else {
         format!("{}s", secs)
     }
+}
+
+/// Ring the terminal bell and send a desktop notification for a finished operation
+pub fn notify_completion(title: &str, message: &str, success: bool) {
+    eprint!("\x07");
+    io::stderr().flush().ok();
+    
+    let summary = if success {
+        format!("✓ {}", title)
+    } else {
+        format!("✗ {}", title)
+    };
+    
+    let mut notification = notify_rust::Notification::new();
+    notification
+        .appname("lmo")
+        .summary(&summary)
+        .body(message);
+    
+    // Urgency is only supported by the freedesktop backend
+    #[cfg(all(unix, not(target_os = "macos")))]
+    if !success {
+        notification.urgency(notify_rust::Urgency::Critical);
+    }
+    
+    // Desktop notifications are best-effort (e.g. unavailable over SSH)
+    if let Err(e) = notification.show() {
+        debug!("Failed to show desktop notification: {}", e);
+    }
 }
\ No newline at end of file